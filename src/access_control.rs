@@ -0,0 +1,79 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::types::Role;
+use crate::errors::EscrowError;
+use crate::utils::{Utils, MAX_TTL};
+
+/// Module for role-based access control and two-step admin transfer.
+///
+/// The admin implicitly holds every role; other roles are granted per address.
+pub struct AccessControlModule;
+
+#[contractimpl]
+impl AccessControlModule {
+    /// Grants a role to an address.
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().persistent().set(&("role", &role, &account), &true);
+        env.storage().persistent().extend_ttl(&("role", &role, &account), MAX_TTL, MAX_TTL);
+        Ok(())
+    }
+
+    /// Revokes a role from an address.
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().persistent().remove(&("role", &role, &account));
+        Ok(())
+    }
+
+    /// Gives up a role held by the caller.
+    pub fn renounce_role(env: Env, role: Role, account: Address) {
+        account.require_auth();
+        env.storage().persistent().remove(&("role", &role, &account));
+    }
+
+    /// Checks whether an address holds a role.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&"admin");
+        if admin.as_ref() == Some(&account) {
+            return true;
+        }
+        env.storage().persistent().get(&("role", &role, &account)).unwrap_or(false)
+    }
+
+    /// Retrieves the current admin.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&"admin")
+    }
+
+    /// Proposes a new admin, who must accept before the transfer takes effect.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"pending_admin", &new_admin);
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer.
+    pub fn accept_admin(env: Env) -> Result<(), EscrowError> {
+        let pending: Address = env.storage().instance().get(&"pending_admin").ok_or(EscrowError::NoPendingAdmin)?;
+        pending.require_auth();
+
+        env.storage().instance().set(&"admin", &pending);
+        env.storage().instance().remove(&"pending_admin");
+
+        Ok(())
+    }
+}
+
+impl AccessControlModule {
+    /// Requires the caller's authorization and that it holds the given role.
+    pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), EscrowError> {
+        caller.require_auth();
+        if !Self::has_role(env.clone(), role, caller.clone()) {
+            return Err(EscrowError::Unauthorized);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::types::TokenAccounting;
+use crate::errors::EscrowError;
+use crate::utils::Utils;
+
+/// Module for tracking what the contract owes in each token.
+pub struct AccountingModule;
+
+#[contractimpl]
+impl AccountingModule {
+    /// Retrieves the amounts owed in a token.
+    pub fn get_accounting(env: Env, token: Address) -> TokenAccounting {
+        env.storage().persistent().get(&("accounting", &token)).unwrap_or_default()
+    }
+
+    /// Fails if the contract's balance of a token is below what it owes.
+    pub fn assert_solvent(env: Env, token: Address) -> Result<(), EscrowError> {
+        if Self::surplus(&env, &token) < 0 {
+            return Err(EscrowError::Insolvent);
+        }
+        Ok(())
+    }
+
+    /// Sends tokens held beyond what the contract owes to `to`.
+    ///
+    /// Only surplus, such as tokens sent to the contract by mistake, can be
+    /// rescued; escrowed funds, fees, bonds and stakes are never touched.
+    pub fn rescue(env: Env, token: Address, to: Address, amount: i128) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+
+        if amount <= 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+        if amount > Self::surplus(&env, &token) {
+            return Err(EscrowError::RescueExceedsSurplus);
+        }
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+
+        Ok(())
+    }
+}
+
+impl AccountingModule {
+    /// Adjusts the escrowed total for a token by `delta`.
+    pub fn add_escrowed(env: &Env, token: &Address, delta: i128) {
+        Self::update(env, token, |accounting| accounting.escrowed += delta);
+    }
+
+    /// Adjusts the dispute bond total for a token by `delta`.
+    pub fn add_dispute_bonds(env: &Env, token: &Address, delta: i128) {
+        Self::update(env, token, |accounting| accounting.dispute_bonds += delta);
+    }
+
+    /// Adjusts the arbitrator stake total for a token by `delta`.
+    pub fn add_stakes(env: &Env, token: &Address, delta: i128) {
+        Self::update(env, token, |accounting| accounting.stakes += delta);
+    }
+
+    /// Contract balance of a token minus what it owes.
+    fn surplus(env: &Env, token: &Address) -> i128 {
+        let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+        balance - Self::get_accounting(env.clone(), token.clone()).total()
+    }
+
+    fn update(env: &Env, token: &Address, apply: impl FnOnce(&mut TokenAccounting)) {
+        let mut accounting = Self::get_accounting(env.clone(), token.clone());
+        apply(&mut accounting);
+        env.storage().persistent().set(&("accounting", token), &accounting);
+        Utils::extend_shared_ttl(env, &("accounting", token));
+    }
+}
@@ -2,8 +2,15 @@
 
 use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
 
-use crate::types::{Agreement, Transaction};
+use crate::types::{Agreement, Operation, TransactionState};
 use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::bonds::BondModule;
+use crate::limits::LimitsModule;
+use crate::pause::PauseModule;
+use crate::utils::Utils;
+use crate::index::IndexModule;
+use crate::tokens::TokenModule;
 
 /// Module for managing transaction agreements.
 pub struct AgreementModule;
@@ -11,39 +18,206 @@ pub struct AgreementModule;
 #[contractimpl]
 impl AgreementModule {
     /// Creates a new agreement with specified terms.
+    ///
+    /// IDs starting with `esc_` are reserved for contract-assigned escrows.
     pub fn create_agreement(
         env: Env,
         transaction_id: Symbol,
         buyer: Address,
         seller: Address,
+        token: Address,
         amount: i128,
         deadline: u64,
     ) -> Result<(), EscrowError> {
-        // Ensure the transaction ID is unique.
-        if env.storage().has(&transaction_id) {
+        if Utils::is_reserved_id(&env, &transaction_id) {
+            return Err(EscrowError::ReservedTransactionId);
+        }
+        Self::open_agreement(&env, transaction_id, buyer, seller, token, amount, deadline)
+    }
+
+    /// Creates a new agreement under a contract-assigned ID and returns the ID.
+    ///
+    /// IDs increase monotonically from 1. Callers cannot choose IDs in this
+    /// range, so they cannot be squatted.
+    pub fn create_escrow(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<u64, EscrowError> {
+        let escrow_id: u64 = env.storage().instance().get(&"next_escrow_id").unwrap_or(1);
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::open_agreement(&env, transaction_id, buyer, seller, token, amount, deadline)?;
+        env.storage().instance().set(&"next_escrow_id", &(escrow_id + 1));
+
+        Ok(escrow_id)
+    }
+
+    /// Cancels an unfunded escrow by contract-assigned escrow ID.
+    pub fn cancel_escrow_by_id(env: Env, escrow_id: u64, party: Address) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::cancel_escrow(env, transaction_id, party)
+    }
+
+    /// Cancels an escrow that has not been funded, releasing both parties'
+    /// exposure and returning any bonds already posted. Either party may cancel.
+    pub fn cancel_escrow(env: Env, transaction_id: Symbol, party: Address) -> Result<(), EscrowError> {
+        let mut transaction = Utils::load_transaction(&env, &transaction_id)?;
+        if transaction.state != TransactionState::Setup {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        if party != transaction.buyer && party != transaction.seller {
+            return Err(EscrowError::Unauthorized);
+        }
+        party.require_auth();
+
+        transaction.state = TransactionState::Cancelled;
+        env.storage().persistent().set(&transaction_id, &transaction);
+        Utils::extend_escrow_ttl(&env, &transaction_id, transaction.deadline);
+        IndexModule::set_state(&env, &transaction_id, TransactionState::Cancelled);
+        LimitsModule::close_escrow(&env, &transaction.buyer, &transaction.seller, transaction.amount, false);
+        BondModule::return_bonds(&env, &transaction);
+
+        // Record the cancellation action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "cancel_escrow");
+
+        Ok(())
+    }
+
+    /// Returns the transaction ID under which a contract-assigned escrow is
+    /// stored, for use with entrypoints keyed by transaction ID.
+    pub fn escrow_key(env: Env, escrow_id: u64) -> Symbol {
+        Utils::escrow_symbol(&env, escrow_id)
+    }
+
+    /// Retrieves an existing agreement by contract-assigned escrow ID.
+    pub fn get_agreement_by_id(env: Env, escrow_id: u64) -> Result<Agreement, EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_agreement(env, transaction_id)
+    }
+
+    /// Retrieves an existing agreement by transaction ID.
+    ///
+    /// Funded escrows are stored as transactions, and are returned as their agreement terms.
+    pub fn get_agreement(env: Env, transaction_id: Symbol) -> Result<Agreement, EscrowError> {
+        // Retrieve the agreement from storage.
+        let transaction = Utils::load_transaction(&env, &transaction_id).map_err(|_| EscrowError::AgreementNotFound)?;
+        Utils::extend_escrow_ttl(&env, &transaction_id, transaction.deadline);
+        Ok(Agreement {
+            buyer: transaction.buyer,
+            seller: transaction.seller,
+            token: transaction.token,
+            amount: transaction.amount,
+            deadline: transaction.deadline,
+            state: transaction.state,
+        })
+    }
+
+    /// Extends the lifetime of an escrow's records by contract-assigned escrow ID.
+    pub fn bump_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::bump(env, transaction_id)
+    }
+
+    /// Extends the lifetime of an escrow's records. Anyone may call this.
+    ///
+    /// The records the escrow shares with others, namely both parties'
+    /// exposure, the indexes listing it and its token's accounting, are
+    /// extended too.
+    pub fn bump(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        let deadline = transaction.deadline;
+        Utils::extend_escrow_ttl(&env, &transaction_id, deadline);
+
+        let storage = env.storage().persistent();
+
+        // The auto-release record lives until the later of the two times.
+        let auto_release_key = ("auto_release", &transaction_id);
+        if let Some(auto_release_at) = storage.get::<_, u64>(&auto_release_key) {
+            Utils::extend_escrow_ttl(&env, &auto_release_key, deadline.max(auto_release_at));
+        }
+
+        for name in [
+            "release_approval",
+            "compliance_approval",
+            "dispute",
+            "oracle",
+            "hashlock",
+            "payees",
+            "legs",
+            "bonds",
+        ] {
+            let key = (name, &transaction_id);
+            if storage.has(&key) {
+                Utils::extend_escrow_ttl(&env, &key, deadline);
+            }
+        }
+
+        IndexModule::extend_ttl(&env, &transaction_id, &transaction.buyer, &transaction.seller);
+        for key in [("exposure", &transaction.buyer), ("exposure", &transaction.seller)] {
+            if storage.has(&key) {
+                Utils::extend_shared_ttl(&env, &key);
+            }
+        }
+        if storage.has(&("accounting", &transaction.token)) {
+            Utils::extend_shared_ttl(&env, &("accounting", &transaction.token));
+        }
+
+        Utils::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+}
+
+impl AgreementModule {
+    /// Stores a new agreement under the given ID after running the creation checks.
+    fn open_agreement(
+        env: &Env,
+        transaction_id: Symbol,
+        buyer: Address,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), EscrowError> {
+        // Ensure the transaction ID is unique, including among subscriptions.
+        if env.storage().persistent().has(&transaction_id) || env.storage().persistent().has(&("subscription", &transaction_id)) {
             return Err(EscrowError::AgreementAlreadyExists);
         }
 
+        // Refuse new escrows while creation is paused.
+        PauseModule::ensure_not_paused(env, Operation::Creation, &token)?;
+
+        // Only allow-listed tokens and positive amounts may be escrowed.
+        TokenModule::validate(env, &token, amount)?;
+
+        // Both parties must agree before the escrow counts against their limits.
+        buyer.require_auth();
+        seller.require_auth();
+
+        // Enforce trade limits and record the new exposure for both parties.
+        LimitsModule::open_escrow(env, &buyer, &seller, &token, amount)?;
+
+        // Make the escrow discoverable by party and state.
+        IndexModule::add_escrow(env, &transaction_id, &buyer, &seller, TransactionState::Setup);
+
         // Create a new agreement.
         let agreement = Agreement {
             buyer,
             seller,
+            token,
             amount,
             deadline,
             state: TransactionState::Setup,
         };
 
-        // Store the agreement in the contract's storage.
-        env.storage().set(&transaction_id, &agreement);
+        // Store the agreement in the contract's storage, keeping it alive past its deadline.
+        env.storage().persistent().set(&transaction_id, &agreement);
+        Utils::extend_escrow_ttl(env, &transaction_id, deadline);
+        Utils::extend_instance_ttl(env);
 
         Ok(())
     }
-
-    /// Retrieves an existing agreement by transaction ID.
-    pub fn get_agreement(env: Env, transaction_id: Symbol) -> Result<Agreement, EscrowError> {
-        // Retrieve the agreement from storage.
-        env.storage()
-            .get(&transaction_id)
-            .ok_or(EscrowError::AgreementNotFound)
-    }
 }
@@ -0,0 +1,204 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::types::{ReleaseApproval, Transaction, TransactionState};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::utils::Utils;
+
+/// Module for collecting extra approvals before high-value releases.
+pub struct ApprovalModule;
+
+#[contractimpl]
+impl ApprovalModule {
+    /// Sets the amount at or above which releases require extra approvers.
+    pub fn set_high_value_threshold(env: Env, amount: i128) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"high_value_threshold", &amount);
+        Ok(())
+    }
+
+    /// Sets the M-of-N compliance approvers every high-value release needs.
+    ///
+    /// Parties cannot replace these; their own approvers only add to them.
+    pub fn set_compliance_approvers(env: Env, approvers: Vec<Address>, threshold: u32) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        Self::validate_approvers(&approvers, threshold)?;
+
+        let policy = ReleaseApproval {
+            approvers,
+            threshold,
+            approvals: Vec::new(&env),
+        };
+        env.storage().instance().set(&"compliance_approvers", &policy);
+        Ok(())
+    }
+
+    /// Attaches M-of-N release approvers to an escrow by contract-assigned escrow ID.
+    pub fn set_release_approvers_by_id(
+        env: Env,
+        escrow_id: u64,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_release_approvers(env, transaction_id, approvers, threshold)
+    }
+
+    /// Attaches M-of-N release approvers to an escrow.
+    ///
+    /// Both parties must authorize the policy, and it can only be set before
+    /// the funds are released and before anyone has approved.
+    pub fn set_release_approvers(
+        env: Env,
+        transaction_id: Symbol,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup && transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        Self::validate_approvers(&approvers, threshold)?;
+        if let Some(existing) = Self::get_release_approval(env.clone(), transaction_id.clone()) {
+            if !existing.approvals.is_empty() {
+                return Err(EscrowError::ApprovalsStarted);
+            }
+        }
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        let approval = ReleaseApproval {
+            approvers,
+            threshold,
+            approvals: Vec::new(&env),
+        };
+        env.storage().persistent().set(&("release_approval", &transaction_id), &approval);
+        Utils::extend_escrow_ttl(&env, &("release_approval", &transaction_id), 0);
+
+        Ok(())
+    }
+
+    /// Records an approver's sign-off by contract-assigned escrow ID.
+    pub fn approve_release_by_id(env: Env, escrow_id: u64, approver: Address) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::approve_release(env, transaction_id, approver)
+    }
+
+    /// Records an approver's sign-off on releasing an escrow.
+    pub fn approve_release(env: Env, transaction_id: Symbol, approver: Address) -> Result<(), EscrowError> {
+        let mut approval: ReleaseApproval = env
+            .storage()
+            .persistent()
+            .get(&("release_approval", &transaction_id))
+            .ok_or(EscrowError::NotAnApprover)?;
+
+        if !approval.approvers.contains(&approver) {
+            return Err(EscrowError::NotAnApprover);
+        }
+        if approval.approvals.contains(&approver) {
+            return Err(EscrowError::AlreadyApproved);
+        }
+
+        approver.require_auth();
+
+        approval.approvals.push_back(approver);
+        env.storage().persistent().set(&("release_approval", &transaction_id), &approval);
+
+        // Record the approval action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "approve_release");
+
+        Ok(())
+    }
+
+    /// Records a compliance approver's sign-off by contract-assigned escrow ID.
+    pub fn approve_compliance_by_id(env: Env, escrow_id: u64, approver: Address) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::approve_compliance(env, transaction_id, approver)
+    }
+
+    /// Records a compliance approver's sign-off on a high-value release.
+    pub fn approve_compliance(env: Env, transaction_id: Symbol, approver: Address) -> Result<(), EscrowError> {
+        let policy = Self::compliance_policy(&env).ok_or(EscrowError::NotAnApprover)?;
+        if !policy.approvers.contains(&approver) {
+            return Err(EscrowError::NotAnApprover);
+        }
+
+        let key = ("compliance_approval", &transaction_id);
+        let mut approvals: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        if approvals.contains(&approver) {
+            return Err(EscrowError::AlreadyApproved);
+        }
+
+        approver.require_auth();
+
+        approvals.push_back(approver);
+        env.storage().persistent().set(&key, &approvals);
+        Utils::extend_escrow_ttl(&env, &key, 0);
+
+        AuditModule::record_action(&env, &transaction_id, "approve_compliance");
+
+        Ok(())
+    }
+
+    /// Retrieves the release approvers by contract-assigned escrow ID.
+    pub fn get_release_approval_by_id(env: Env, escrow_id: u64) -> Option<ReleaseApproval> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_release_approval(env, transaction_id)
+    }
+
+    /// Retrieves the release approvers attached to an escrow, if any.
+    pub fn get_release_approval(env: Env, transaction_id: Symbol) -> Option<ReleaseApproval> {
+        env.storage().persistent().get(&("release_approval", &transaction_id))
+    }
+}
+
+impl ApprovalModule {
+    /// Fails unless the escrow has collected enough approvals to be released.
+    ///
+    /// High-value escrows also need the admin-configured compliance approvals,
+    /// whatever policy the parties attached.
+    pub fn ensure_release_approved(env: &Env, transaction: &Transaction) -> Result<(), EscrowError> {
+        if let Some(approval) = Self::get_release_approval(env.clone(), transaction.id.clone()) {
+            if approval.approvals.len() < approval.threshold {
+                return Err(EscrowError::ApprovalThresholdNotMet);
+            }
+        }
+
+        let high_value: Option<i128> = env.storage().instance().get(&"high_value_threshold");
+        if matches!(high_value, Some(limit) if transaction.amount >= limit) {
+            let policy = Self::compliance_policy(env).ok_or(EscrowError::ApprovalThresholdNotMet)?;
+            let approvals: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&("compliance_approval", &transaction.id))
+                .unwrap_or(Vec::new(env));
+            // Only count approvers still on the compliance list.
+            let count = approvals.iter().filter(|a| policy.approvers.contains(a)).count() as u32;
+            if count < policy.threshold {
+                return Err(EscrowError::ApprovalThresholdNotMet);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the approvers are distinct and the threshold is reachable.
+    fn validate_approvers(approvers: &Vec<Address>, threshold: u32) -> Result<(), EscrowError> {
+        if threshold == 0 || threshold > approvers.len() {
+            return Err(EscrowError::InvalidThreshold);
+        }
+        for approver in approvers.iter() {
+            if approvers.iter().filter(|other| other == &approver).count() > 1 {
+                return Err(EscrowError::DuplicateApprover);
+            }
+        }
+        Ok(())
+    }
+
+    fn compliance_policy(env: &Env) -> Option<ReleaseApproval> {
+        env.storage().instance().get(&"compliance_approvers")
+    }
+}
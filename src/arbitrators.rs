@@ -0,0 +1,323 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Vec};
+
+use crate::types::{Arbitrator, ArbitratorStaking, DisputeReason, Role};
+use crate::errors::EscrowError;
+use crate::access_control::AccessControlModule;
+use crate::accounting::AccountingModule;
+use crate::utils::{Utils, MAX_TTL};
+
+/// Module for the registry of arbitrators disputes are routed to.
+///
+/// Arbitrators are vetted by an arbitrator manager and must then stake at
+/// least the minimum stake before disputes are routed to them. Stakes can be
+/// slashed, and withdrawn after a cooldown once no open disputes remain.
+pub struct ArbitratorModule;
+
+#[contractimpl]
+impl ArbitratorModule {
+    /// Sets the token, minimum stake and unstaking cooldown for arbitrators.
+    ///
+    /// The staking token can only change once every stake has been withdrawn.
+    pub fn set_arbitrator_staking(env: Env, staking: ArbitratorStaking) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        if staking.min_stake < 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+        if let Ok(current) = Self::staking(&env) {
+            if current.token != staking.token && AccountingModule::get_accounting(env.clone(), current.token).stakes > 0 {
+                return Err(EscrowError::StakesOutstanding);
+            }
+        }
+        env.storage().instance().set(&"arbitrator_staking", &staking);
+        Ok(())
+    }
+
+    /// Sets how many arbitrators are drawn for each dispute. The size must be
+    /// odd so a full panel cannot split evenly.
+    pub fn set_panel_size(env: Env, size: u32) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        if size % 2 == 0 {
+            return Err(EscrowError::InvalidThreshold);
+        }
+        env.storage().instance().set(&"panel_size", &size);
+        Ok(())
+    }
+
+    /// Sets how many open disputes an arbitrator may hold before no more are assigned to them.
+    pub fn set_max_open_disputes(env: Env, limit: Option<u32>) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"max_open_disputes", &limit);
+        Ok(())
+    }
+
+    /// Records that an arbitrator is tied to a party and must not rule on their disputes.
+    ///
+    /// Arbitrators may declare their own conflicts; arbitrator managers may
+    /// declare them for anyone.
+    pub fn declare_conflict(env: Env, caller: Address, arbitrator: Address, party: Address) -> Result<(), EscrowError> {
+        if caller == arbitrator {
+            caller.require_auth();
+        } else {
+            AccessControlModule::require_role(&env, &caller, Role::ArbitratorManager)?;
+        }
+
+        let key = ("conflict", &arbitrator, &party);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, MAX_TTL, MAX_TTL);
+
+        Ok(())
+    }
+
+    /// Checks whether an arbitrator is tied to a party.
+    pub fn has_conflict(env: Env, arbitrator: Address, party: Address) -> bool {
+        arbitrator == party || env.storage().persistent().get(&("conflict", &arbitrator, &party)).unwrap_or(false)
+    }
+
+    /// Registers an arbitrator, or updates the specialties of a registered one.
+    pub fn register_arbitrator(
+        env: Env,
+        caller: Address,
+        arbitrator: Address,
+        specialties: Vec<DisputeReason>,
+    ) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::ArbitratorManager)?;
+
+        let mut registry = Self::list_arbitrators(env.clone());
+        if !registry.contains(&arbitrator) {
+            registry.push_back(arbitrator.clone());
+            Self::save_registry(&env, &registry);
+        }
+
+        let record = match Self::get_arbitrator(env.clone(), arbitrator.clone()) {
+            Some(existing) => Arbitrator { specialties, ..existing },
+            None => Arbitrator {
+                address: arbitrator,
+                specialties,
+                stake: 0,
+                open_disputes: 0,
+                unstake_at: None,
+            },
+        };
+        Self::save(&env, &record);
+
+        Ok(())
+    }
+
+    /// Removes an arbitrator from the registry, returning any remaining stake.
+    ///
+    /// Arbitrators with open disputes must see them closed first, so their
+    /// stake stays slashable and no panel is left with a removed member.
+    pub fn remove_arbitrator(env: Env, caller: Address, arbitrator: Address) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::ArbitratorManager)?;
+
+        let mut registry = Self::list_arbitrators(env.clone());
+        let position = registry.first_index_of(&arbitrator).ok_or(EscrowError::ArbitratorNotFound)?;
+        let mut record = Self::load(&env, &arbitrator)?;
+        if record.open_disputes > 0 {
+            return Err(EscrowError::ArbitratorBusy);
+        }
+        let stake = record.stake;
+        Self::pay_stake(&env, &mut record, &arbitrator, stake)?;
+
+        registry.remove(position);
+        Self::save_registry(&env, &registry);
+        env.storage().persistent().remove(&("arbitrator", &arbitrator));
+
+        Ok(())
+    }
+
+    /// Adds to a registered arbitrator's stake.
+    ///
+    /// Staking again cancels a pending unstake request.
+    pub fn stake(env: Env, arbitrator: Address, amount: i128) -> Result<(), EscrowError> {
+        let staking = Self::staking(&env)?;
+        let mut record = Self::load(&env, &arbitrator)?;
+        if amount <= 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+
+        arbitrator.require_auth();
+        token::Client::new(&env, &staking.token).transfer(&arbitrator, &env.current_contract_address(), &amount);
+        AccountingModule::add_stakes(&env, &staking.token, amount);
+
+        record.stake = record.stake.checked_add(amount).ok_or(EscrowError::AmountOverflow)?;
+        record.unstake_at = None;
+        Self::save(&env, &record);
+
+        Ok(())
+    }
+
+    /// Starts the cooldown after which an arbitrator can withdraw their stake.
+    ///
+    /// No new disputes are routed to the arbitrator once this is called.
+    pub fn request_unstake(env: Env, arbitrator: Address) -> Result<(), EscrowError> {
+        let staking = Self::staking(&env)?;
+        let mut record = Self::load(&env, &arbitrator)?;
+
+        arbitrator.require_auth();
+
+        record.unstake_at = Some(Utils::calculate_deadline(&env, staking.cooldown));
+        Self::save(&env, &record);
+
+        Ok(())
+    }
+
+    /// Withdraws an arbitrator's whole stake once the cooldown has passed and
+    /// none of their assigned disputes are open.
+    pub fn unstake(env: Env, arbitrator: Address) -> Result<(), EscrowError> {
+        let mut record = Self::load(&env, &arbitrator)?;
+        let unstake_at = record.unstake_at.ok_or(EscrowError::DeadlineNotReached)?;
+
+        arbitrator.require_auth();
+
+        if !Utils::is_past_deadline(&env, unstake_at) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
+        if record.open_disputes > 0 {
+            return Err(EscrowError::ArbitratorBusy);
+        }
+
+        let stake = record.stake;
+        Self::pay_stake(&env, &mut record, &arbitrator, stake)?;
+        record.unstake_at = None;
+        Self::save(&env, &record);
+
+        Ok(())
+    }
+
+    /// Slashes up to `amount` of an arbitrator's stake, sending it to `recipient`.
+    pub fn slash_arbitrator(env: Env, arbitrator: Address, amount: i128, recipient: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        Self::slash(&env, &arbitrator, amount, &recipient)
+    }
+
+    /// Retrieves a registered arbitrator.
+    pub fn get_arbitrator(env: Env, arbitrator: Address) -> Option<Arbitrator> {
+        env.storage().persistent().get(&("arbitrator", &arbitrator))
+    }
+
+    /// Lists every registered arbitrator in registration order.
+    pub fn list_arbitrators(env: Env) -> Vec<Address> {
+        env.storage().persistent().get(&"arbitrators").unwrap_or(Vec::new(&env))
+    }
+}
+
+impl ArbitratorModule {
+    /// Draws a panel for a dispute between `buyer` and `seller` using the ledger PRNG.
+    ///
+    /// Candidates are the active arbitrators specializing in `reason` with no
+    /// conflict with either party. They are drawn without replacement,
+    /// weighted by stake, until the configured panel size is reached or the
+    /// candidates run out. A short panel with an even count drops its last
+    /// member so its votes cannot tie.
+    pub fn select_panel(env: &Env, reason: &DisputeReason, buyer: &Address, seller: &Address) -> Vec<Address> {
+        let registry = Self::list_arbitrators(env.clone());
+        // Bounding each weight by an even share of u64 keeps the total from overflowing.
+        let max_weight = (u64::MAX / registry.len().max(1) as u64) as i128;
+        let mut candidates = Vec::new(env);
+        let mut weights: Vec<u64> = Vec::new(env);
+        for address in registry.iter() {
+            let Some(arbitrator) = Self::get_arbitrator(env.clone(), address.clone()) else {
+                continue;
+            };
+            let eligible = Self::is_active(env, &arbitrator)
+                && arbitrator.specialties.contains(reason)
+                && !Self::has_conflict(env.clone(), address.clone(), buyer.clone())
+                && !Self::has_conflict(env.clone(), address.clone(), seller.clone());
+            if eligible {
+                candidates.push_back(address);
+                weights.push_back(arbitrator.stake.clamp(1, max_weight) as u64);
+            }
+        }
+
+        let size: u32 = env.storage().instance().get(&"panel_size").unwrap_or(1);
+        let mut panel = Vec::new(env);
+        while panel.len() < size && !candidates.is_empty() {
+            let total: u64 = weights.iter().sum();
+            let mut pick = env.prng().gen_range::<u64>(0..total);
+            let mut index = 0;
+            while pick >= weights.get_unchecked(index) {
+                pick -= weights.get_unchecked(index);
+                index += 1;
+            }
+            panel.push_back(candidates.get_unchecked(index));
+            candidates.remove(index);
+            weights.remove(index);
+        }
+        if panel.len() % 2 == 0 {
+            panel.pop_back();
+        }
+        panel
+    }
+
+    /// Whether disputes may be routed to an arbitrator.
+    ///
+    /// Arbitrators must hold the minimum stake, not be unstaking and be under
+    /// the open dispute limit. Until staking is configured every registered
+    /// arbitrator meets the stake requirement.
+    pub fn is_active(env: &Env, arbitrator: &Arbitrator) -> bool {
+        let min_stake = Self::staking(env).map_or(0, |staking| staking.min_stake);
+        let max_open: Option<u32> = env.storage().instance().get(&"max_open_disputes").unwrap_or(None);
+        arbitrator.unstake_at.is_none()
+            && arbitrator.stake >= min_stake
+            && max_open.map_or(true, |limit| arbitrator.open_disputes < limit)
+    }
+
+    /// Counts a newly assigned open dispute against an arbitrator.
+    pub fn open_case(env: &Env, arbitrator: &Address) {
+        if let Some(mut record) = Self::get_arbitrator(env.clone(), arbitrator.clone()) {
+            record.open_disputes += 1;
+            Self::save(env, &record);
+        }
+    }
+
+    /// Releases an assigned dispute once it is closed.
+    pub fn close_case(env: &Env, arbitrator: &Address) {
+        if let Some(mut record) = Self::get_arbitrator(env.clone(), arbitrator.clone()) {
+            record.open_disputes = record.open_disputes.saturating_sub(1);
+            Self::save(env, &record);
+        }
+    }
+
+    /// Moves up to `amount` of an arbitrator's stake to `recipient`.
+    pub fn slash(env: &Env, arbitrator: &Address, amount: i128, recipient: &Address) -> Result<(), EscrowError> {
+        if amount <= 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+        let mut record = Self::load(env, arbitrator)?;
+        let slashed = amount.min(record.stake);
+        Self::pay_stake(env, &mut record, recipient, slashed)?;
+        Self::save(env, &record);
+        Ok(())
+    }
+
+    fn pay_stake(env: &Env, record: &mut Arbitrator, recipient: &Address, amount: i128) -> Result<(), EscrowError> {
+        if amount > 0 {
+            let staking = Self::staking(env)?;
+            token::Client::new(env, &staking.token).transfer(&env.current_contract_address(), recipient, &amount);
+            AccountingModule::add_stakes(env, &staking.token, -amount);
+            record.stake -= amount;
+        }
+        Ok(())
+    }
+
+    fn staking(env: &Env) -> Result<ArbitratorStaking, EscrowError> {
+        env.storage().instance().get(&"arbitrator_staking").ok_or(EscrowError::StakingNotSet)
+    }
+
+    fn load(env: &Env, arbitrator: &Address) -> Result<Arbitrator, EscrowError> {
+        Self::get_arbitrator(env.clone(), arbitrator.clone()).ok_or(EscrowError::ArbitratorNotFound)
+    }
+
+    fn save(env: &Env, record: &Arbitrator) {
+        env.storage().persistent().set(&("arbitrator", &record.address), record);
+        env.storage().persistent().extend_ttl(&("arbitrator", &record.address), MAX_TTL, MAX_TTL);
+    }
+
+    fn save_registry(env: &Env, registry: &Vec<Address>) {
+        env.storage().persistent().set(&"arbitrators", registry);
+        env.storage().persistent().extend_ttl(&"arbitrators", MAX_TTL, MAX_TTL);
+    }
+}
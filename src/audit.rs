@@ -2,26 +2,45 @@
 
 use soroban_sdk::{contract, contractimpl, Env, Symbol};
 
+use crate::index::MAX_PAGE_SIZE;
+use crate::utils::Utils;
+
 /// Module for auditing actions within the escrow service.
 pub struct AuditModule;
 
 #[contractimpl]
 impl AuditModule {
     /// Records an action taken on a transaction for audit purposes.
+    ///
+    /// Each entry is stored under its own sequence number, so the log can keep
+    /// growing without any single record reaching the ledger entry size limit.
     pub fn record_action(env: Env, transaction_id: &Symbol, action: &str) {
         // Construct the audit log entry.
         let log_entry = format!("Transaction: {}, Action: {}", transaction_id, action);
 
-        // Store the log entry in the contract's storage.
-        // For simplicity, we append the log entry to a list of logs.
-        let mut logs: Vec<String> = env.storage().get(&"audit_logs").unwrap_or_default();
-        logs.push(log_entry);
-        env.storage().set(&"audit_logs", &logs);
+        // Store the log entry under the next sequence number.
+        let sequence: u64 = env.storage().instance().get(&"audit_count").unwrap_or(0);
+        let key = ("audit_log", sequence);
+        env.storage().persistent().set(&key, &log_entry);
+        Utils::extend_escrow_ttl(&env, &key, 0);
+        env.storage().instance().set(&"audit_count", &(sequence + 1));
     }
 
-    /// Retrieves the audit logs for inspection.
-    pub fn get_audit_logs(env: Env) -> Vec<String> {
+    /// Retrieves up to `limit` audit log entries, oldest first, starting at
+    /// sequence number `cursor`. Entries that have expired are skipped.
+    pub fn get_audit_logs(env: Env, cursor: u64, limit: u32) -> Vec<String> {
+        let count: u64 = env.storage().instance().get(&"audit_count").unwrap_or(0);
+        let limit = limit.min(MAX_PAGE_SIZE) as usize;
+
         // Retrieve the audit logs from storage.
-        env.storage().get(&"audit_logs").unwrap_or_default()
+        let mut logs = Vec::new();
+        let mut sequence = cursor;
+        while sequence < count && logs.len() < limit {
+            if let Some(log_entry) = env.storage().persistent().get(&("audit_log", sequence)) {
+                logs.push(log_entry);
+            }
+            sequence += 1;
+        }
+        logs
     }
 }
@@ -0,0 +1,119 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Env, Symbol, Vec};
+
+use crate::types::{EscrowLeg, Operation, Transaction, TransactionState};
+use crate::errors::EscrowError;
+use crate::accounting::AccountingModule;
+use crate::pause::PauseModule;
+use crate::payees::{PayeeModule, BPS_DENOMINATOR};
+use crate::tokens::TokenModule;
+use crate::utils::Utils;
+
+/// Module for basket escrows that hold several tokens.
+///
+/// The transaction's own `token` and `amount` form the first leg; extra legs
+/// are deposited alongside it by `deposit_funds` and paid out leg by leg on
+/// release, refund and dispute settlement.
+pub struct BasketModule;
+
+#[contractimpl]
+impl BasketModule {
+    /// Adds extra legs to an escrow by contract-assigned escrow ID.
+    pub fn set_legs_by_id(env: Env, escrow_id: u64, legs: Vec<EscrowLeg>) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_legs(env, transaction_id, legs)
+    }
+
+    /// Adds extra (token, amount) legs to an escrow before it is funded.
+    ///
+    /// Both parties must authorize the legs.
+    pub fn set_legs(env: Env, transaction_id: Symbol, legs: Vec<EscrowLeg>) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        for leg in legs.iter() {
+            TokenModule::validate(&env, &leg.token, leg.amount)?;
+        }
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        env.storage().persistent().set(&("legs", &transaction_id), &legs);
+        Utils::extend_escrow_ttl(&env, &("legs", &transaction_id), transaction.deadline);
+
+        Ok(())
+    }
+
+    /// Retrieves the extra legs of an escrow by contract-assigned escrow ID.
+    pub fn get_legs_by_id(env: Env, escrow_id: u64) -> Vec<EscrowLeg> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_legs(env, transaction_id)
+    }
+
+    /// Retrieves the extra legs of an escrow.
+    pub fn get_legs(env: Env, transaction_id: Symbol) -> Vec<EscrowLeg> {
+        env.storage()
+            .persistent()
+            .get(&("legs", &transaction_id))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+impl BasketModule {
+    /// Fails if the operation is paused for any extra leg's token.
+    ///
+    /// Run before any transfer, so a paused leg cannot leave the escrow half
+    /// moved.
+    pub fn ensure_legs_not_paused(env: &Env, transaction: &Transaction, operation: Operation) -> Result<(), EscrowError> {
+        for leg in Self::get_legs(env.clone(), transaction.id.clone()).iter() {
+            PauseModule::ensure_not_paused(env, operation.clone(), &leg.token)?;
+        }
+        Ok(())
+    }
+
+    /// Transfers every extra leg from the buyer to the contract.
+    pub fn deposit_legs(env: &Env, transaction: &Transaction) {
+        for leg in Self::get_legs(env.clone(), transaction.id.clone()).iter() {
+            token::Client::new(env, &leg.token).transfer(&transaction.buyer, &env.current_contract_address(), &leg.amount);
+            AccountingModule::add_escrowed(env, &leg.token, leg.amount);
+        }
+    }
+
+    /// Pays every extra leg to the seller, or across the escrow's payees.
+    pub fn release_legs(env: &Env, transaction: &Transaction) {
+        Self::settle_legs(env, transaction, BPS_DENOMINATOR);
+    }
+
+    /// Returns every extra leg to the buyer. Never paused, so funds cannot be trapped.
+    pub fn refund_legs(env: &Env, transaction: &Transaction) {
+        Self::settle_legs(env, transaction, 0);
+    }
+
+    /// Pays `seller_bps` of every extra leg to the seller, or across the
+    /// escrow's payees, and the remainder to the buyer.
+    ///
+    /// Rounding dust goes to the buyer, so no part of a leg stays in the
+    /// contract. Never paused, so funds cannot be trapped.
+    pub fn settle_legs(env: &Env, transaction: &Transaction, seller_bps: u32) {
+        let split = PayeeModule::get_payees(env.clone(), transaction.id.clone());
+        for leg in Self::get_legs(env.clone(), transaction.id.clone()).iter() {
+            let token_client = token::Client::new(env, &leg.token);
+            let seller_amount = leg.amount * seller_bps as i128 / BPS_DENOMINATOR as i128;
+            let buyer_amount = leg.amount - seller_amount;
+
+            if seller_amount > 0 {
+                match &split {
+                    Some(split) => PayeeModule::transfer_split(env, &leg.token, split, seller_amount),
+                    None => token_client.transfer(&env.current_contract_address(), &transaction.seller, &seller_amount),
+                }
+            }
+            if buyer_amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &transaction.buyer, &buyer_amount);
+            }
+            AccountingModule::add_escrowed(env, &leg.token, -leg.amount);
+        }
+    }
+}
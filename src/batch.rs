@@ -0,0 +1,100 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Env, Symbol, Vec};
+
+use crate::agreement::AgreementModule;
+use crate::fund_management::FundManagementModule;
+use crate::types::{BatchItemResult, EscrowTerms};
+use crate::errors::EscrowError;
+
+/// Maximum number of items processed by a single batch call.
+pub const MAX_BATCH_SIZE: u32 = 25;
+
+/// Module for processing many escrows in one call.
+///
+/// Atomic batches fail as a whole on the first error. Non-atomic batches
+/// report a result per item and are not rolled back when an item fails, so
+/// the single-item operations they call must run every check before moving
+/// any funds.
+pub struct BatchModule;
+
+#[contractimpl]
+impl BatchModule {
+    /// Creates an escrow for each set of terms and returns the assigned IDs.
+    ///
+    /// Creation is always atomic so the returned IDs line up with `terms`.
+    pub fn create_escrows(env: Env, terms: Vec<EscrowTerms>) -> Result<Vec<u64>, EscrowError> {
+        Self::check_size(terms.len())?;
+
+        let mut escrow_ids = Vec::new(&env);
+        for item in terms.iter() {
+            let escrow_id = AgreementModule::create_escrow(
+                env.clone(),
+                item.buyer,
+                item.seller,
+                item.token,
+                item.amount,
+                item.deadline,
+            )?;
+            escrow_ids.push_back(escrow_id);
+        }
+
+        Ok(escrow_ids)
+    }
+
+    /// Releases funds for each transaction. The buyer of each must authorize.
+    ///
+    /// Escrows gated by an oracle or a hashlock are released through
+    /// `attest` and `claim_with_preimage` instead.
+    pub fn release_many(
+        env: Env,
+        transaction_ids: Vec<Symbol>,
+        atomic: bool,
+    ) -> Result<Vec<BatchItemResult>, EscrowError> {
+        Self::run(&env, &transaction_ids, atomic, |env, transaction_id| {
+            FundManagementModule::release_escrow(env.clone(), transaction_id.clone())
+        })
+    }
+
+    /// Refunds each expired transaction.
+    pub fn expire_many(
+        env: Env,
+        transaction_ids: Vec<Symbol>,
+        atomic: bool,
+    ) -> Result<Vec<BatchItemResult>, EscrowError> {
+        Self::run(&env, &transaction_ids, atomic, |env, transaction_id| {
+            FundManagementModule::expire(env.clone(), transaction_id.clone())
+        })
+    }
+}
+
+impl BatchModule {
+    fn check_size(len: u32) -> Result<(), EscrowError> {
+        if len > MAX_BATCH_SIZE {
+            return Err(EscrowError::BatchTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Applies `operation` to each ID, collecting per-item results or failing
+    /// on the first error when `atomic` is set.
+    fn run(
+        env: &Env,
+        transaction_ids: &Vec<Symbol>,
+        atomic: bool,
+        operation: impl Fn(&Env, &Symbol) -> Result<(), EscrowError>,
+    ) -> Result<Vec<BatchItemResult>, EscrowError> {
+        Self::check_size(transaction_ids.len())?;
+
+        let mut results = Vec::new(env);
+        for transaction_id in transaction_ids.iter() {
+            match operation(env, &transaction_id) {
+                Ok(()) => results.push_back(BatchItemResult::Ok),
+                Err(error) if atomic => return Err(error),
+                Err(error) => results.push_back(BatchItemResult::Err(error)),
+            }
+        }
+
+        Ok(results)
+    }
+}
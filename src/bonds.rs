@@ -0,0 +1,189 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol};
+
+use crate::types::{Bond, EscrowBonds, SlashTarget, Transaction, TransactionState};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::accounting::AccountingModule;
+use crate::tokens::TokenModule;
+use crate::utils::Utils;
+
+/// Module for security deposits posted by both parties of an escrow.
+///
+/// Bonds must be posted before the escrow is funded. They are returned when
+/// the escrow completes cleanly and slashed when a party loses a dispute or
+/// misses a deadline.
+pub struct BondModule;
+
+#[contractimpl]
+impl BondModule {
+    /// Sets where slashed bonds are sent. Treasury slashing requires a treasury address.
+    pub fn set_slash_target(env: Env, target: SlashTarget, treasury: Option<Address>) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        if target == SlashTarget::Treasury && treasury.is_none() {
+            return Err(EscrowError::Unauthorized);
+        }
+        env.storage().instance().set(&"slash_target", &target);
+        env.storage().instance().set(&"treasury", &treasury);
+        Ok(())
+    }
+
+    /// Requires both parties to post bonds by contract-assigned escrow ID.
+    pub fn require_bonds_by_id(
+        env: Env,
+        escrow_id: u64,
+        token: Address,
+        buyer_amount: i128,
+        seller_amount: i128,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::require_bonds(env, transaction_id, token, buyer_amount, seller_amount)
+    }
+
+    /// Requires both parties to post bonds in `token` before the escrow is funded.
+    ///
+    /// Both parties must authorize the bonds, which can only be required once.
+    pub fn require_bonds(
+        env: Env,
+        transaction_id: Symbol,
+        token: Address,
+        buyer_amount: i128,
+        seller_amount: i128,
+    ) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        if Self::get_bonds(env.clone(), transaction_id.clone()).is_some() {
+            return Err(EscrowError::BondsAlreadyRequired);
+        }
+        TokenModule::validate(&env, &token, buyer_amount)?;
+        TokenModule::validate(&env, &token, seller_amount)?;
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        let bonds = EscrowBonds {
+            buyer_bond: Bond { party: transaction.buyer.clone(), token: token.clone(), amount: buyer_amount, posted: false },
+            seller_bond: Bond { party: transaction.seller.clone(), token, amount: seller_amount, posted: false },
+        };
+        Self::save(&env, &transaction_id, &bonds, transaction.deadline);
+
+        Ok(())
+    }
+
+    /// Posts a party's bond by contract-assigned escrow ID.
+    pub fn post_bond_by_id(env: Env, escrow_id: u64, party: Address) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::post_bond(env, transaction_id, party)
+    }
+
+    /// Posts a party's bond. Bonds can only be posted before the escrow is funded.
+    pub fn post_bond(env: Env, transaction_id: Symbol, party: Address) -> Result<(), EscrowError> {
+        let mut bonds = Self::get_bonds(env.clone(), transaction_id.clone()).ok_or(EscrowError::BondsNotPosted)?;
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+
+        let bond = if party == bonds.buyer_bond.party {
+            &mut bonds.buyer_bond
+        } else if party == bonds.seller_bond.party {
+            &mut bonds.seller_bond
+        } else {
+            return Err(EscrowError::Unauthorized);
+        };
+        if bond.posted {
+            return Err(EscrowError::AlreadyFunded);
+        }
+
+        party.require_auth();
+        token::Client::new(&env, &bond.token).transfer(&party, &env.current_contract_address(), &bond.amount);
+        AccountingModule::add_escrowed(&env, &bond.token, bond.amount);
+        bond.posted = true;
+
+        Self::save(&env, &transaction_id, &bonds, transaction.deadline);
+        AuditModule::record_action(&env, &transaction_id, "post_bond");
+
+        Ok(())
+    }
+
+    /// Retrieves the bonds attached to an escrow by contract-assigned escrow ID.
+    pub fn get_bonds_by_id(env: Env, escrow_id: u64) -> Option<EscrowBonds> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_bonds(env, transaction_id)
+    }
+
+    /// Retrieves the bonds attached to an escrow, if any.
+    pub fn get_bonds(env: Env, transaction_id: Symbol) -> Option<EscrowBonds> {
+        env.storage().persistent().get(&("bonds", &transaction_id))
+    }
+}
+
+impl BondModule {
+    /// Fails unless every bond required by the escrow has been posted.
+    pub fn ensure_posted(env: &Env, transaction: &Transaction) -> Result<(), EscrowError> {
+        if let Some(bonds) = Self::get_bonds(env.clone(), transaction.id.clone()) {
+            if !bonds.buyer_bond.posted || !bonds.seller_bond.posted {
+                return Err(EscrowError::BondsNotPosted);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns both bonds to the parties that posted them.
+    pub fn return_bonds(env: &Env, transaction: &Transaction) {
+        if let Some(mut bonds) = Self::get_bonds(env.clone(), transaction.id.clone()) {
+            for bond in [&mut bonds.buyer_bond, &mut bonds.seller_bond] {
+                Self::pay_bond(env, bond, &bond.party.clone());
+            }
+            Self::save(env, &transaction.id, &bonds, transaction.deadline);
+        }
+    }
+
+    /// Slashes `loser`'s bond and returns the other party's bond.
+    ///
+    /// The slashed bond goes to the counterparty, or to the treasury when the
+    /// admin has chosen treasury slashing.
+    pub fn slash_bond(env: &Env, transaction: &Transaction, loser: &Address) {
+        let Some(mut bonds) = Self::get_bonds(env.clone(), transaction.id.clone()) else {
+            return;
+        };
+
+        let target: SlashTarget = env.storage().instance().get(&"slash_target").unwrap_or(SlashTarget::Counterparty);
+        let treasury: Option<Address> = env.storage().instance().get(&"treasury").unwrap_or(None);
+
+        for bond in [&mut bonds.buyer_bond, &mut bonds.seller_bond] {
+            let party = bond.party.clone();
+            let recipient = if &party != loser {
+                party
+            } else {
+                match (&target, &treasury) {
+                    (SlashTarget::Treasury, Some(treasury)) => treasury.clone(),
+                    _ if party == transaction.buyer => transaction.seller.clone(),
+                    _ => transaction.buyer.clone(),
+                }
+            };
+            Self::pay_bond(env, bond, &recipient);
+        }
+        Self::save(env, &transaction.id, &bonds, transaction.deadline);
+    }
+
+    /// Transfers a posted bond out of the contract and marks it settled.
+    fn pay_bond(env: &Env, bond: &mut Bond, recipient: &Address) {
+        if !bond.posted {
+            return;
+        }
+        token::Client::new(env, &bond.token).transfer(&env.current_contract_address(), recipient, &bond.amount);
+        AccountingModule::add_escrowed(env, &bond.token, -bond.amount);
+        bond.posted = false;
+    }
+
+    fn save(env: &Env, transaction_id: &Symbol, bonds: &EscrowBonds, deadline: u64) {
+        env.storage().persistent().set(&("bonds", transaction_id), bonds);
+        Utils::extend_escrow_ttl(env, &("bonds", transaction_id), deadline);
+    }
+}
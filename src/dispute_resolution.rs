@@ -1,39 +1,117 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Symbol, Vec};
 
-use crate::types::{Dispute, Transaction, TransactionState};
+use crate::types::{
+    ArbitratorMetrics, ClaimedRemedy, Dispute, DisputeBondAmount, DisputeBondPolicy, DisputeBondRecipient,
+    DisputeReason, DisputeState, PanelVote, Transaction, TransactionState,
+};
 use crate::errors::EscrowError;
 use crate::audit::AuditModule;
+use crate::accounting::AccountingModule;
+use crate::fund_management::FundManagementModule;
+use crate::utils::{Utils, MAX_TTL};
+use crate::index::IndexModule;
+use crate::bonds::BondModule;
+use crate::arbitrators::ArbitratorModule;
+use crate::payees::BPS_DENOMINATOR;
+
+/// Time a panel has to decide a dispute when no ruling timeout is set: 30 days.
+pub const DEFAULT_RULING_TIMEOUT: u64 = 30 * 24 * 60 * 60;
 
 /// Module for handling disputes and arbitration.
 pub struct DisputeResolutionModule;
 
 #[contractimpl]
 impl DisputeResolutionModule {
-    /// Raises a dispute for a given transaction.
-    pub fn raise_dispute(env: Env, transaction_id: Symbol, raiser: Address) -> Result<(), EscrowError> {
+    /// Sets the bond a party must deposit to raise a dispute. Percentages are in basis points of the escrow amount.
+    pub fn set_dispute_bond(env: Env, policy: Option<DisputeBondPolicy>) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+
+        match &policy {
+            Some(DisputeBondPolicy { amount: DisputeBondAmount::Flat(amount), .. }) if *amount < 0 => {
+                return Err(EscrowError::InvalidAmount);
+            }
+            Some(DisputeBondPolicy { amount: DisputeBondAmount::Percentage(bps), .. }) if *bps > BPS_DENOMINATOR => {
+                return Err(EscrowError::InvalidShares);
+            }
+            _ => {}
+        }
+
+        env.storage().instance().set(&"dispute_bond", &policy);
+        Ok(())
+    }
+
+    /// Sets the appeal panel whose members may overturn rulings.
+    pub fn set_appeal_panel(env: Env, members: Vec<Address>) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"appeal_panel", &members);
+        Ok(())
+    }
+
+    /// Raises a dispute by contract-assigned escrow ID.
+    pub fn raise_dispute_by_id(
+        env: Env,
+        escrow_id: u64,
+        raiser: Address,
+        reason: DisputeReason,
+        remedy: ClaimedRemedy,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::raise_dispute(env, transaction_id, raiser, reason, remedy)
+    }
+
+    /// Raises a dispute for a given transaction, stating why and what the raiser claims.
+    ///
+    /// The raiser deposits the configured dispute bond in the escrow token.
+    /// A panel is drawn at random from the arbitrators specializing in the
+    /// reason; the dispute cannot be raised if none is eligible.
+    pub fn raise_dispute(
+        env: Env,
+        transaction_id: Symbol,
+        raiser: Address,
+        reason: DisputeReason,
+        remedy: ClaimedRemedy,
+    ) -> Result<(), EscrowError> {
         // Retrieve the transaction details from storage.
-        let mut transaction: Transaction = env.storage().get(&transaction_id).ok_or(EscrowError::TransactionNotFound)?;
+        let mut transaction = Utils::load_transaction(&env, &transaction_id)?;
 
         // Ensure the transaction is in a state that allows disputes.
         if transaction.state != TransactionState::Deposit {
             return Err(EscrowError::InvalidTransactionState);
         }
+        if raiser != transaction.buyer && raiser != transaction.seller {
+            return Err(EscrowError::Unauthorized);
+        }
+        raiser.require_auth();
 
-        // Create a new dispute.
-        let dispute = Dispute {
-            transaction_id: transaction_id.clone(),
+        // Create a new dispute, assigning it to a panel and taking the bond.
+        let dispute = Self::open(
+            &env,
+            &transaction_id,
+            &transaction.token,
+            transaction.amount,
+            &transaction.buyer,
+            &transaction.seller,
             raiser,
-            state: DisputeState::Open,
-        };
+            reason,
+            remedy,
+        )?;
 
         // Store the dispute in the contract's storage.
-        env.storage().set(&transaction_id, &dispute);
+        Self::save(&env, &dispute, transaction.deadline);
 
         // Update the transaction state to indicate a dispute has been raised.
         transaction.state = TransactionState::Dispute;
-        env.storage().set(&transaction_id, &transaction);
+        env.storage().persistent().set(&transaction_id, &transaction);
+        Utils::extend_escrow_ttl(&env, &transaction_id, transaction.deadline);
+        IndexModule::set_state(&env, &transaction_id, TransactionState::Dispute);
+
+        // Announce the claim so the parties and arbitrators can follow it.
+        env.events().publish(
+            (Symbol::new(&env, "dispute_raised"), transaction_id.clone()),
+            (dispute.raiser.clone(), dispute.reason.clone(), dispute.remedy.clone(), dispute.panel.clone()),
+        );
 
         // Record the dispute action for audit purposes.
         AuditModule::record_action(&env, &transaction_id, "raise_dispute");
@@ -41,32 +119,444 @@ impl DisputeResolutionModule {
         Ok(())
     }
 
-    /// Resolves a dispute through arbitration.
-    pub fn resolve_dispute(env: Env, transaction_id: Symbol, arbitrator: Address) -> Result<(), EscrowError> {
+    /// Casts an arbitrator's vote on a dispute by contract-assigned escrow ID.
+    pub fn resolve_dispute_by_id(
+        env: Env,
+        escrow_id: u64,
+        arbitrator: Address,
+        release_to_seller: bool,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::resolve_dispute(env, transaction_id, arbitrator, release_to_seller)
+    }
+
+    /// Casts an arbitrator's vote on a dispute, paying the escrow to the
+    /// seller or refunding the buyer once a majority of the panel agrees.
+    ///
+    /// Only panel members may vote. The raiser's dispute bond is refunded if
+    /// the ruling favors them and paid to the counterparty, or the arbitrator
+    /// casting the deciding vote, otherwise.
+    pub fn resolve_dispute(
+        env: Env,
+        transaction_id: Symbol,
+        arbitrator: Address,
+        release_to_seller: bool,
+    ) -> Result<(), EscrowError> {
         // Retrieve the dispute details from storage.
-        let mut dispute: Dispute = env.storage().get(&transaction_id).ok_or(EscrowError::DisputeNotFound)?;
+        let mut dispute = Self::get_dispute(env.clone(), transaction_id.clone())?;
+
+        // Record the vote, and stop until a majority agrees.
+        let decided = Self::cast_vote(&mut dispute, &arbitrator, release_to_seller)?;
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        if !decided {
+            Self::save(&env, &dispute, transaction.deadline);
+            AuditModule::record_action(&env, &transaction_id, "vote_dispute");
+            return Ok(());
+        }
 
+        Self::execute_ruling(&env, &mut dispute, &transaction, release_to_seller, &arbitrator);
+
+        // Record the resolution action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "resolve_dispute");
+
+        Ok(())
+    }
+
+    /// Sets how long a panel has to decide a dispute before anyone can end it
+    /// with `rule_on_timeout`, in seconds.
+    pub fn set_ruling_timeout(env: Env, timeout: u64) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"ruling_timeout", &timeout);
+        Ok(())
+    }
+
+    /// Ends an undecided dispute by contract-assigned escrow ID.
+    pub fn rule_on_timeout_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::rule_on_timeout(env, transaction_id)
+    }
+
+    /// Ends a dispute the panel has not decided within the ruling timeout.
+    /// Anyone may call this.
+    ///
+    /// The side with more votes wins, and the last vote for it counts as the
+    /// deciding one. A tie, including no votes at all, splits the escrow
+    /// evenly and returns every bond.
+    pub fn rule_on_timeout(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        let mut dispute = Self::get_dispute(env.clone(), transaction_id.clone())?;
+        let ruling = Self::timed_out_ruling(&env, &dispute)?;
+
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        match ruling {
+            Some((release_to_seller, decider)) => {
+                Self::execute_ruling(&env, &mut dispute, &transaction, release_to_seller, &decider);
+            }
+            None => {
+                let seller_bps = BPS_DENOMINATOR / 2;
+                FundManagementModule::settle(&env, &transaction, seller_bps);
+                BondModule::return_bonds(&env, &transaction);
+                let raiser = dispute.raiser.clone();
+                Self::close(&env, &mut dispute, &transaction.token, &raiser, DisputeState::TimedOut, Some(seller_bps));
+                Self::save(&env, &dispute, transaction.deadline);
+            }
+        }
+
+        AuditModule::record_action(&env, &transaction_id, "rule_on_timeout");
+
+        Ok(())
+    }
+
+    /// Withdraws an open dispute by contract-assigned escrow ID.
+    pub fn withdraw_dispute_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::withdraw_dispute(env, transaction_id)
+    }
+
+    /// Withdraws an open dispute, returning the escrow to the funded state.
+    ///
+    /// Only the raiser may withdraw, and only before any arbitrator has voted.
+    /// The dispute bond is forfeited to the counterparty, so raising and
+    /// withdrawing cannot be used to stall a release for free.
+    pub fn withdraw_dispute(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        let mut dispute = Self::get_dispute(env.clone(), transaction_id.clone())?;
+        if dispute.state != DisputeState::Open || !dispute.votes.is_empty() {
+            return Err(EscrowError::InvalidDisputeState);
+        }
+
+        dispute.raiser.require_auth();
+
+        let mut transaction = Utils::load_transaction(&env, &transaction_id)?;
+        let counterparty = if dispute.raiser == transaction.buyer {
+            transaction.seller.clone()
+        } else {
+            transaction.buyer.clone()
+        };
+        Self::close(&env, &mut dispute, &transaction.token, &counterparty, DisputeState::Withdrawn, None);
+        Self::save(&env, &dispute, transaction.deadline);
+
+        transaction.state = TransactionState::Deposit;
+        env.storage().persistent().set(&transaction_id, &transaction);
+        Utils::extend_escrow_ttl(&env, &transaction_id, transaction.deadline);
+        IndexModule::set_state(&env, &transaction_id, TransactionState::Deposit);
+
+        AuditModule::record_action(&env, &transaction_id, "withdraw_dispute");
+
+        Ok(())
+    }
+
+    /// Settles an open dispute by contract-assigned escrow ID.
+    pub fn settle_dispute_by_id(env: Env, escrow_id: u64, seller_bps: u32) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::settle_dispute(env, transaction_id, seller_bps)
+    }
+
+    /// Settles an open dispute on terms both parties agree to, without an arbitrator.
+    ///
+    /// Both parties must authorize the settlement. `seller_bps` of the escrow
+    /// is paid to the seller and the rest refunded to the buyer. Collateral
+    /// bonds and the dispute bond are returned to the parties that posted them.
+    pub fn settle_dispute(env: Env, transaction_id: Symbol, seller_bps: u32) -> Result<(), EscrowError> {
+        let mut dispute = Self::get_dispute(env.clone(), transaction_id.clone())?;
+        if dispute.state != DisputeState::Open {
+            return Err(EscrowError::InvalidDisputeState);
+        }
+        if seller_bps > BPS_DENOMINATOR {
+            return Err(EscrowError::InvalidShares);
+        }
+
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        FundManagementModule::settle(&env, &transaction, seller_bps);
+        BondModule::return_bonds(&env, &transaction);
+        let raiser = dispute.raiser.clone();
+        Self::close(&env, &mut dispute, &transaction.token, &raiser, DisputeState::Settled, Some(seller_bps));
+        Self::save(&env, &dispute, transaction.deadline);
+
+        AuditModule::record_action(&env, &transaction_id, "settle_dispute");
+
+        Ok(())
+    }
+
+    /// Overturns a ruling on appeal by contract-assigned escrow ID.
+    pub fn overturn_ruling_by_id(
+        env: Env,
+        panel_member: Address,
+        escrow_id: u64,
+        slash_amount: i128,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::overturn_ruling(env, panel_member, transaction_id, slash_amount)
+    }
+
+    /// Overturns a ruling on appeal, slashing up to `slash_amount` of the stake
+    /// of each arbitrator who voted for it to the party it went against.
+    ///
+    /// Only appeal panel members may overturn rulings. Funds already paid out
+    /// under the ruling are not moved.
+    pub fn overturn_ruling(
+        env: Env,
+        panel_member: Address,
+        transaction_id: Symbol,
+        slash_amount: i128,
+    ) -> Result<(), EscrowError> {
+        let panel: Vec<Address> = env.storage().instance().get(&"appeal_panel").unwrap_or(Vec::new(&env));
+        if !panel.contains(&panel_member) {
+            return Err(EscrowError::Unauthorized);
+        }
+        panel_member.require_auth();
+
+        let mut dispute = Self::get_dispute(env.clone(), transaction_id.clone())?;
+        if dispute.state != DisputeState::Resolved {
+            return Err(EscrowError::InvalidDisputeState);
+        }
+
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        let released = dispute.seller_bps == Some(BPS_DENOMINATOR);
+        let wronged = if released { &transaction.buyer } else { &transaction.seller };
+        for vote in dispute.votes.iter().filter(|vote| vote.release_to_seller == released) {
+            // Arbitrators removed since the ruling have no stake left to slash.
+            if ArbitratorModule::get_arbitrator(env.clone(), vote.arbitrator.clone()).is_some() {
+                ArbitratorModule::slash(&env, &vote.arbitrator, slash_amount, wronged)?;
+            }
+            Self::update_metrics(&env, &vote.arbitrator, |metrics| metrics.overturned += 1);
+        }
+
+        dispute.state = DisputeState::Overturned;
+        Self::save(&env, &dispute, transaction.deadline);
+
+        AuditModule::record_action(&env, &transaction_id, "overturn_ruling");
+
+        Ok(())
+    }
+
+    /// Retrieves an arbitrator's workload and performance counters.
+    pub fn get_arbitrator_metrics(env: Env, arbitrator: Address) -> ArbitratorMetrics {
+        env.storage()
+            .persistent()
+            .get(&("arbitrator_metrics", &arbitrator))
+            .unwrap_or_default()
+    }
+
+    /// Retrieves the dispute raised on an escrow by contract-assigned escrow ID.
+    pub fn get_dispute_by_id(env: Env, escrow_id: u64) -> Result<Dispute, EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_dispute(env, transaction_id)
+    }
+
+    /// Retrieves the dispute raised on a transaction.
+    pub fn get_dispute(env: Env, transaction_id: Symbol) -> Result<Dispute, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&("dispute", &transaction_id))
+            .ok_or(EscrowError::DisputeNotFound)
+    }
+}
+
+impl DisputeResolutionModule {
+    /// Opens a dispute over `amount` of `token` held between `buyer` and
+    /// `seller`, drawing a panel by the reason and taking the raiser's bond.
+    ///
+    /// The caller checks the raiser is a party, authorizes them and stores
+    /// the returned dispute.
+    #[allow(clippy::too_many_arguments)]
+    pub fn open(
+        env: &Env,
+        transaction_id: &Symbol,
+        token: &Address,
+        amount: i128,
+        buyer: &Address,
+        seller: &Address,
+        raiser: Address,
+        reason: DisputeReason,
+        remedy: ClaimedRemedy,
+    ) -> Result<Dispute, EscrowError> {
+        if let ClaimedRemedy::Split(seller_bps) = remedy {
+            if seller_bps > BPS_DENOMINATOR {
+                return Err(EscrowError::InvalidShares);
+            }
+        }
+
+        // Draw a panel by the dispute's reason before taking any funds.
+        let panel = ArbitratorModule::select_panel(env, &reason, buyer, seller);
+        if panel.is_empty() {
+            return Err(EscrowError::NoArbitratorAvailable);
+        }
+
+        // Take the dispute bond from the raiser.
+        let bond = Self::bond_amount(env, amount);
+        if bond > 0 {
+            token::Client::new(env, token).transfer(&raiser, &env.current_contract_address(), &bond);
+            AccountingModule::add_dispute_bonds(env, token, bond);
+        }
+
+        for member in panel.iter() {
+            ArbitratorModule::open_case(env, &member);
+            Self::update_metrics(env, &member, |metrics| metrics.assigned += 1);
+        }
+        Ok(Dispute {
+            transaction_id: transaction_id.clone(),
+            raiser,
+            reason,
+            remedy,
+            panel,
+            votes: Vec::new(env),
+            raised_at: env.ledger().timestamp(),
+            state: DisputeState::Open,
+            bond,
+            seller_bps: None,
+        })
+    }
+
+    /// Records a panel member's vote, returning whether it gave one side a
+    /// majority of the panel.
+    pub fn cast_vote(dispute: &mut Dispute, arbitrator: &Address, release_to_seller: bool) -> Result<bool, EscrowError> {
         // Ensure the dispute is open.
         if dispute.state != DisputeState::Open {
             return Err(EscrowError::InvalidDisputeState);
         }
 
-        // Authorize the arbitrator.
+        // Authorize the arbitrator, who must sit on the panel.
+        if !dispute.panel.contains(arbitrator) {
+            return Err(EscrowError::Unauthorized);
+        }
+        if dispute.votes.iter().any(|vote| &vote.arbitrator == arbitrator) {
+            return Err(EscrowError::AlreadyVoted);
+        }
         arbitrator.require_auth();
 
-        // Resolve the dispute (logic for resolution would be implemented here).
-        // For simplicity, we assume the dispute is resolved in favor of the seller.
-        let mut transaction: Transaction = env.storage().get(&transaction_id).ok_or(EscrowError::TransactionNotFound)?;
-        transaction.state = TransactionState::Complete;
-        env.storage().set(&transaction_id, &transaction);
+        dispute.votes.push_back(PanelVote { arbitrator: arbitrator.clone(), release_to_seller });
+        let agreeing = dispute.votes.iter().filter(|vote| vote.release_to_seller == release_to_seller).count() as u32;
+        Ok(agreeing >= dispute.panel.len() / 2 + 1)
+    }
+
+    /// Works out the ruling for a dispute whose ruling timeout has passed.
+    ///
+    /// The side with more votes wins, and the last vote for it counts as the
+    /// deciding one. Returns `None` on a tie, including no votes at all.
+    pub fn timed_out_ruling(env: &Env, dispute: &Dispute) -> Result<Option<(bool, Address)>, EscrowError> {
+        if dispute.state != DisputeState::Open {
+            return Err(EscrowError::InvalidDisputeState);
+        }
+        let timeout: u64 = env.storage().instance().get(&"ruling_timeout").unwrap_or(DEFAULT_RULING_TIMEOUT);
+        if !Utils::is_past_deadline(env, dispute.raised_at.saturating_add(timeout)) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
 
-        // Update the dispute state to indicate it has been resolved.
-        dispute.state = DisputeState::Resolved;
-        env.storage().set(&transaction_id, &dispute);
+        let for_seller = dispute.votes.iter().filter(|vote| vote.release_to_seller).count();
+        let for_buyer = dispute.votes.len() as usize - for_seller;
+        if for_seller == for_buyer {
+            return Ok(None);
+        }
 
-        // Record the resolution action for audit purposes.
-        AuditModule::record_action(&env, &transaction_id, "resolve_dispute");
+        let release_to_seller = for_seller > for_buyer;
+        let decider = dispute
+            .votes
+            .iter()
+            .filter(|vote| vote.release_to_seller == release_to_seller)
+            .last()
+            .unwrap()
+            .arbitrator;
+        Ok(Some((release_to_seller, decider)))
+    }
 
-        Ok(())
+    /// Picks who receives the dispute bond under a ruling for `winner`: a
+    /// winning raiser gets it back, otherwise it is forfeited.
+    ///
+    /// `decider` is the arbitrator whose vote decided the ruling.
+    pub fn ruling_bond_recipient(env: &Env, dispute: &Dispute, winner: &Address, decider: &Address) -> Address {
+        if &dispute.raiser == winner {
+            return winner.clone();
+        }
+        match Self::bond_policy(env).map(|policy| policy.recipient) {
+            Some(DisputeBondRecipient::Arbitrator) => decider.clone(),
+            _ => winner.clone(),
+        }
+    }
+
+    /// Ends a dispute: pays its bond to `bond_recipient`, closes the panel's
+    /// cases and, for a ruling, credits the voters. The caller stores it.
+    pub fn close(
+        env: &Env,
+        dispute: &mut Dispute,
+        token: &Address,
+        bond_recipient: &Address,
+        state: DisputeState,
+        seller_bps: Option<u32>,
+    ) {
+        Self::pay_bond(env, token, dispute, bond_recipient);
+
+        for member in dispute.panel.iter() {
+            ArbitratorModule::close_case(env, &member);
+        }
+        if state == DisputeState::Resolved {
+            let ruling_time = env.ledger().timestamp().saturating_sub(dispute.raised_at);
+            for vote in dispute.votes.iter() {
+                Self::update_metrics(env, &vote.arbitrator, |metrics| {
+                    metrics.resolved += 1;
+                    metrics.total_ruling_time += ruling_time;
+                    metrics.average_ruling_time = metrics.total_ruling_time / metrics.resolved as u64;
+                });
+            }
+        }
+        dispute.state = state;
+        dispute.seller_bps = seller_bps;
+    }
+
+    /// Carries out a ruling: pays out the escrow, slashes the loser's bond,
+    /// settles the dispute bond and credits the voters.
+    ///
+    /// `decider` is the arbitrator whose vote decided the ruling.
+    fn execute_ruling(env: &Env, dispute: &mut Dispute, transaction: &Transaction, release_to_seller: bool, decider: &Address) {
+        let (winner, loser) = if release_to_seller {
+            (&transaction.seller, &transaction.buyer)
+        } else {
+            (&transaction.buyer, &transaction.seller)
+        };
+        let seller_bps = if release_to_seller { BPS_DENOMINATOR } else { 0 };
+        FundManagementModule::settle(env, transaction, seller_bps);
+
+        // The losing party's collateral bond is slashed.
+        BondModule::slash_bond(env, transaction, loser);
+
+        let bond_recipient = Self::ruling_bond_recipient(env, dispute, winner, decider);
+        Self::close(env, dispute, &transaction.token, &bond_recipient, DisputeState::Resolved, Some(seller_bps));
+        Self::save(env, dispute, transaction.deadline);
+    }
+
+    fn bond_policy(env: &Env) -> Option<DisputeBondPolicy> {
+        env.storage().instance().get(&"dispute_bond").unwrap_or(None)
+    }
+
+    /// Dispute bond owed for raising a dispute over `amount`.
+    fn bond_amount(env: &Env, amount: i128) -> i128 {
+        match Self::bond_policy(env).map(|policy| policy.amount) {
+            Some(DisputeBondAmount::Flat(amount)) => amount,
+            Some(DisputeBondAmount::Percentage(bps)) => amount * bps as i128 / BPS_DENOMINATOR as i128,
+            None => 0,
+        }
+    }
+
+    /// Pays out a dispute's bond.
+    fn pay_bond(env: &Env, token: &Address, dispute: &Dispute, recipient: &Address) {
+        if dispute.bond > 0 {
+            token::Client::new(env, token).transfer(&env.current_contract_address(), recipient, &dispute.bond);
+            AccountingModule::add_dispute_bonds(env, token, -dispute.bond);
+        }
+    }
+
+    fn update_metrics(env: &Env, arbitrator: &Address, apply: impl FnOnce(&mut ArbitratorMetrics)) {
+        let mut metrics = Self::get_arbitrator_metrics(env.clone(), arbitrator.clone());
+        apply(&mut metrics);
+        let key = ("arbitrator_metrics", arbitrator);
+        env.storage().persistent().set(&key, &metrics);
+        env.storage().persistent().extend_ttl(&key, MAX_TTL, MAX_TTL);
+    }
+
+    fn save(env: &Env, dispute: &Dispute, deadline: u64) {
+        let key = ("dispute", &dispute.transaction_id);
+        env.storage().persistent().set(&key, dispute);
+        Utils::extend_escrow_ttl(env, &key, deadline);
     }
 }
@@ -4,7 +4,7 @@ use soroban_sdk::{contracttype, Env, Symbol};
 
 /// Enum representing possible errors in the escrow service.
 #[contracttype]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EscrowError {
     AgreementAlreadyExists,
     AgreementNotFound,
@@ -15,6 +15,50 @@ pub enum EscrowError {
     Unauthorized,
     InsufficientFunds,
     DeadlineExceeded,
+    AlreadyInitialized,
+    NotInitialized,
+    AmountBelowMinimum,
+    AmountAboveMaximum,
+    OpenEscrowLimitExceeded,
+    OpenValueLimitExceeded,
+    ContractPaused,
+    BatchTooLarge,
+    NoPendingAdmin,
+    InvalidThreshold,
+    NotAnApprover,
+    AlreadyApproved,
+    ApprovalThresholdNotMet,
+    InvalidAmount,
+    AmountOverflow,
+    TokenNotAllowed,
+    Insolvent,
+    RescueExceedsSurplus,
+    DeadlineNotReached,
+    SubscriptionNotFound,
+    InvalidInstallment,
+    SubscriptionCancelled,
+    AutoReleaseNotSet,
+    OracleNotSet,
+    AlreadyAttested,
+    HashlockNotSet,
+    InvalidPreimage,
+    InvalidShares,
+    SwapNotFound,
+    AlreadyFunded,
+    BondsNotPosted,
+    ArbitratorNotFound,
+    StakingNotSet,
+    ArbitratorBusy,
+    AlreadyVoted,
+    SelfEscrow,
+    ApprovalsStarted,
+    ReservedTransactionId,
+    ConditionNotMet,
+    HashlockAlreadySet,
+    BondsAlreadyRequired,
+    StakesOutstanding,
+    NoArbitratorAvailable,
+    DuplicateApprover,
 }
 
 impl EscrowError {
@@ -30,6 +74,50 @@ impl EscrowError {
             EscrowError::Unauthorized => "Unauthorized action",
             EscrowError::InsufficientFunds => "Insufficient funds",
             EscrowError::DeadlineExceeded => "Deadline exceeded",
+            EscrowError::AlreadyInitialized => "Contract already initialized",
+            EscrowError::NotInitialized => "Contract not initialized",
+            EscrowError::AmountBelowMinimum => "Amount below token minimum",
+            EscrowError::AmountAboveMaximum => "Amount above token maximum",
+            EscrowError::OpenEscrowLimitExceeded => "Open escrow limit exceeded",
+            EscrowError::OpenValueLimitExceeded => "Open escrow value limit exceeded",
+            EscrowError::ContractPaused => "Operation is paused",
+            EscrowError::BatchTooLarge => "Batch too large",
+            EscrowError::NoPendingAdmin => "No pending admin transfer",
+            EscrowError::InvalidThreshold => "Invalid approval threshold",
+            EscrowError::NotAnApprover => "Address is not an approver",
+            EscrowError::AlreadyApproved => "Release already approved by address",
+            EscrowError::ApprovalThresholdNotMet => "Release approval threshold not met",
+            EscrowError::InvalidAmount => "Amount must be positive",
+            EscrowError::AmountOverflow => "Amount overflow",
+            EscrowError::TokenNotAllowed => "Token not allowed",
+            EscrowError::Insolvent => "Contract balance below amount owed",
+            EscrowError::RescueExceedsSurplus => "Rescue amount exceeds surplus",
+            EscrowError::DeadlineNotReached => "Deadline not reached",
+            EscrowError::SubscriptionNotFound => "Subscription not found",
+            EscrowError::InvalidInstallment => "Invalid installment",
+            EscrowError::SubscriptionCancelled => "Subscription cancelled",
+            EscrowError::AutoReleaseNotSet => "Auto-release not set",
+            EscrowError::OracleNotSet => "Oracle not set",
+            EscrowError::AlreadyAttested => "Outcome already attested",
+            EscrowError::HashlockNotSet => "Hashlock not set",
+            EscrowError::InvalidPreimage => "Preimage does not match hashlock",
+            EscrowError::InvalidShares => "Payee shares must total 10000 basis points",
+            EscrowError::SwapNotFound => "Swap not found",
+            EscrowError::AlreadyFunded => "Already funded",
+            EscrowError::BondsNotPosted => "Bonds not posted",
+            EscrowError::ArbitratorNotFound => "Arbitrator not found",
+            EscrowError::StakingNotSet => "Arbitrator staking not set",
+            EscrowError::ArbitratorBusy => "Arbitrator has open disputes",
+            EscrowError::AlreadyVoted => "Already voted",
+            EscrowError::SelfEscrow => "Buyer and seller must differ",
+            EscrowError::ApprovalsStarted => "Release approvals already started",
+            EscrowError::ReservedTransactionId => "Transaction ID prefix is reserved",
+            EscrowError::ConditionNotMet => "Release condition not met",
+            EscrowError::HashlockAlreadySet => "Hashlock already set",
+            EscrowError::BondsAlreadyRequired => "Bonds already required",
+            EscrowError::StakesOutstanding => "Arbitrator stakes still held in the staking token",
+            EscrowError::NoArbitratorAvailable => "No eligible arbitrator available",
+            EscrowError::DuplicateApprover => "Approver listed more than once",
         }
     }
 }
@@ -1,10 +1,19 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Symbol};
 
-use crate::types::{Transaction, TransactionState};
+use crate::types::{Operation, Transaction, TransactionState};
 use crate::errors::EscrowError;
 use crate::audit::AuditModule;
+use crate::limits::LimitsModule;
+use crate::pause::PauseModule;
+use crate::approvals::ApprovalModule;
+use crate::utils::Utils;
+use crate::index::IndexModule;
+use crate::accounting::AccountingModule;
+use crate::payees::{PayeeModule, BPS_DENOMINATOR};
+use crate::basket::BasketModule;
+use crate::bonds::BondModule;
 
 /// Module for managing funds in escrow.
 pub struct FundManagementModule;
@@ -17,6 +26,11 @@ impl FundManagementModule {
         if transaction.state != TransactionState::Setup {
             return Err(EscrowError::InvalidTransactionState);
         }
+        PauseModule::ensure_not_paused(&env, Operation::Funding, &transaction.token)?;
+        BasketModule::ensure_legs_not_paused(&env, transaction, Operation::Funding)?;
+
+        // Both parties must have posted any required bonds first.
+        BondModule::ensure_posted(&env, transaction)?;
 
         // Transfer funds from the buyer to the escrow contract.
         let buyer = transaction.buyer;
@@ -26,11 +40,17 @@ impl FundManagementModule {
         // Assuming a token client is available for transferring tokens.
         let token_client = token::Client::new(&env, &transaction.token);
         token_client.transfer(&buyer, &env.current_contract_address(), &amount);
+        AccountingModule::add_escrowed(&env, &transaction.token, amount);
+
+        // Basket escrows are only funded once every extra leg is deposited too.
+        BasketModule::deposit_legs(&env, transaction);
 
         // Update the transaction state to indicate funds have been deposited.
         let mut updated_transaction = transaction.clone();
         updated_transaction.state = TransactionState::Deposit;
-        env.storage().set(&transaction.id, &updated_transaction);
+        env.storage().persistent().set(&transaction.id, &updated_transaction);
+        Utils::extend_escrow_ttl(&env, &transaction.id, transaction.deadline);
+        IndexModule::set_state(&env, &transaction.id, TransactionState::Deposit);
 
         // Record the deposit action for audit purposes.
         AuditModule::record_action(&env, &transaction.id, "deposit_funds");
@@ -38,29 +58,231 @@ impl FundManagementModule {
         Ok(())
     }
 
+    /// Deposits the buyer's funds by contract-assigned escrow ID.
+    pub fn fund_escrow_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::fund_escrow(env, transaction_id)
+    }
+
+    /// Deposits the buyer's funds into the escrow stored under `transaction_id`.
+    ///
+    /// Escrows opened with `create_agreement` or `create_escrow` are funded
+    /// here, and are stored as transactions from then on.
+    pub fn fund_escrow(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        Self::deposit_funds(env, &transaction)
+    }
+
+    /// Releases an escrow to the seller by contract-assigned escrow ID.
+    pub fn release_escrow_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::release_escrow(env, transaction_id)
+    }
+
+    /// Releases the escrow stored under `transaction_id` to the seller. The buyer must authorize.
+    pub fn release_escrow(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        transaction.buyer.require_auth();
+        Self::release_funds(env, &transaction)
+    }
+
     /// Releases funds from escrow to the seller upon fulfillment of conditions.
+    ///
+    /// Escrows with an oracle are released only after a `Fulfilled`
+    /// attestation, and hash-locked escrows only through `claim_with_preimage`.
     pub fn release_funds(env: Env, transaction: &Transaction) -> Result<(), EscrowError> {
+        if !transaction.is_fulfilled(&env) {
+            return Err(EscrowError::ConditionNotMet);
+        }
+        PauseModule::ensure_not_paused(&env, Operation::Release, &transaction.token)?;
+        BasketModule::ensure_legs_not_paused(&env, transaction, Operation::Release)?;
+        Self::release_to_seller(&env, transaction)
+    }
+
+    /// Refunds the buyer of an expired escrow by contract-assigned escrow ID.
+    pub fn expire_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::expire(env, transaction_id)
+    }
+
+    /// Refunds the buyer once a funded escrow's deadline has passed. Anyone may call this.
+    pub fn expire(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        // Retrieve the transaction details from storage.
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        // Ensure the transaction is funded and its deadline has passed.
+        if transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        if !Utils::is_past_deadline(&env, transaction.deadline) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
+
+        // The seller missed the deadline, so their bond is slashed.
+        BondModule::slash_bond(&env, &transaction, &transaction.seller);
+        Self::refund_funds(&env, &transaction);
+
+        // Record the expiry action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "expire");
+
+        Ok(())
+    }
+
+    /// Sets the auto-release time by contract-assigned escrow ID.
+    pub fn set_auto_release_by_id(env: Env, escrow_id: u64, auto_release_at: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_auto_release(env, transaction_id, auto_release_at)
+    }
+
+    /// Sets the time after which the seller can be paid without the buyer's confirmation.
+    ///
+    /// Both parties must authorize it, and it can only be set before the
+    /// funds are released.
+    pub fn set_auto_release(env: Env, transaction_id: Symbol, auto_release_at: u64) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup && transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        env.storage().persistent().set(&("auto_release", &transaction_id), &auto_release_at);
+        Utils::extend_escrow_ttl(&env, &("auto_release", &transaction_id), auto_release_at);
+
+        Ok(())
+    }
+
+    /// Releases funds after the auto-release time by contract-assigned escrow ID.
+    pub fn claim_after_timeout_by_id(env: Env, escrow_id: u64) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::claim_after_timeout(env, transaction_id)
+    }
+
+    /// Releases funds to the seller once the auto-release time has passed
+    /// without a dispute. Anyone may call this.
+    ///
+    /// This is the mirror image of `expire`: a silent buyer lets the seller
+    /// claim, just as a missed deadline lets the buyer be refunded.
+    pub fn claim_after_timeout(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
+        // Retrieve the transaction details from storage.
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        let auto_release_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&("auto_release", &transaction_id))
+            .ok_or(EscrowError::AutoReleaseNotSet)?;
+
+        // A raised dispute moves the transaction out of Deposit, blocking the claim.
+        if transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        if !Utils::is_past_deadline(&env, auto_release_at) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
+
+        Self::release_funds(env.clone(), &transaction)?;
+
+        // Record the claim action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "claim_after_timeout");
+
+        Ok(())
+    }
+}
+
+impl FundManagementModule {
+    /// Releases funds to the seller without checking the escrow's release
+    /// condition or the release pause, for callers that handle both.
+    pub fn release_to_seller(env: &Env, transaction: &Transaction) -> Result<(), EscrowError> {
         // Ensure the transaction is in the correct state for release.
         if transaction.state != TransactionState::Deposit {
             return Err(EscrowError::InvalidTransactionState);
         }
 
-        // Transfer funds from the escrow contract to the seller.
-        let seller = transaction.seller;
+        // High-value escrows need their extra approvers to sign off first.
+        ApprovalModule::ensure_release_approved(env, transaction)?;
+
+        // Transfer funds from the escrow contract to the seller, or split them across the payees.
+        let seller = transaction.seller.clone();
         let amount = transaction.amount;
-        
-        // Assuming a token client is available for transferring tokens.
-        let token_client = token::Client::new(&env, &transaction.token);
-        token_client.transfer(&env.current_contract_address(), &seller, &amount);
+        match PayeeModule::get_payees(env.clone(), transaction.id.clone()) {
+            Some(split) => PayeeModule::transfer_split(env, &transaction.token, &split, amount),
+            None => {
+                let token_client = token::Client::new(env, &transaction.token);
+                token_client.transfer(&env.current_contract_address(), &seller, &amount);
+            }
+        }
+        AccountingModule::add_escrowed(env, &transaction.token, -amount);
+        BasketModule::release_legs(env, transaction);
+        BondModule::return_bonds(env, transaction);
 
         // Update the transaction state to indicate funds have been released.
         let mut updated_transaction = transaction.clone();
         updated_transaction.state = TransactionState::Complete;
-        env.storage().set(&transaction.id, &updated_transaction);
+        env.storage().persistent().set(&transaction.id, &updated_transaction);
+        Utils::extend_escrow_ttl(env, &transaction.id, transaction.deadline);
+        IndexModule::set_state(env, &transaction.id, TransactionState::Complete);
+
+        // Release the parties' exposure and credit them with a completed trade.
+        LimitsModule::close_escrow(env, &transaction.buyer, &seller, amount, true);
 
         // Record the release action for audit purposes.
-        AuditModule::record_action(&env, &transaction.id, "release_funds");
+        AuditModule::record_action(env, &transaction.id, "release_funds");
 
         Ok(())
     }
+
+    /// Returns escrowed funds to the buyer and marks the transaction refunded.
+    ///
+    /// Refunds are never paused, so funds cannot be trapped in the contract.
+    pub fn refund_funds(env: &Env, transaction: &Transaction) {
+        let token_client = token::Client::new(env, &transaction.token);
+        token_client.transfer(&env.current_contract_address(), &transaction.buyer, &transaction.amount);
+        AccountingModule::add_escrowed(env, &transaction.token, -transaction.amount);
+        BasketModule::refund_legs(env, transaction);
+        BondModule::return_bonds(env, transaction);
+
+        let mut updated_transaction = transaction.clone();
+        updated_transaction.state = TransactionState::Refunded;
+        env.storage().persistent().set(&transaction.id, &updated_transaction);
+        Utils::extend_escrow_ttl(env, &transaction.id, transaction.deadline);
+        IndexModule::set_state(env, &transaction.id, TransactionState::Refunded);
+
+        LimitsModule::close_escrow(env, &transaction.buyer, &transaction.seller, transaction.amount, false);
+    }
+
+    /// Pays `seller_bps` of the escrow to the seller and the rest to the buyer.
+    ///
+    /// Used to carry out dispute rulings, so it skips the release approvals
+    /// and pause checks. The escrow ends Refunded if the seller gets nothing
+    /// and Complete otherwise.
+    pub fn settle(env: &Env, transaction: &Transaction, seller_bps: u32) {
+        let seller_amount = transaction.amount * seller_bps as i128 / BPS_DENOMINATOR as i128;
+        let buyer_amount = transaction.amount - seller_amount;
+
+        let token_client = token::Client::new(env, &transaction.token);
+        if seller_amount > 0 {
+            // The seller's share is split across the payees like a release.
+            match PayeeModule::get_payees(env.clone(), transaction.id.clone()) {
+                Some(split) => PayeeModule::transfer_split(env, &transaction.token, &split, seller_amount),
+                None => token_client.transfer(&env.current_contract_address(), &transaction.seller, &seller_amount),
+            }
+        }
+        if buyer_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &transaction.buyer, &buyer_amount);
+        }
+        AccountingModule::add_escrowed(env, &transaction.token, -transaction.amount);
+        BasketModule::settle_legs(env, transaction, seller_bps);
+
+        let state = if seller_bps == 0 { TransactionState::Refunded } else { TransactionState::Complete };
+        let mut updated_transaction = transaction.clone();
+        updated_transaction.state = state.clone();
+        env.storage().persistent().set(&transaction.id, &updated_transaction);
+        Utils::extend_escrow_ttl(env, &transaction.id, transaction.deadline);
+        IndexModule::set_state(env, &transaction.id, state);
+
+        LimitsModule::close_escrow(env, &transaction.buyer, &transaction.seller, transaction.amount, false);
+    }
 }
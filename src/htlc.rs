@@ -0,0 +1,100 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Bytes, BytesN, Env, Symbol};
+
+use crate::types::{HashLock, TransactionState};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::fund_management::FundManagementModule;
+use crate::utils::Utils;
+
+/// Module for hash-locked (HTLC-style) escrows.
+///
+/// The seller claims the funds by revealing the SHA-256 preimage of the
+/// hashlock before the escrow's deadline. After the deadline the buyer is
+/// refunded through `expire`. Claims are never paused, so a pause cannot run
+/// out the seller's window and hand the buyer a refund.
+pub struct HtlcModule;
+
+#[contractimpl]
+impl HtlcModule {
+    /// Locks an escrow behind a hashlock by contract-assigned escrow ID.
+    pub fn set_hashlock_by_id(env: Env, escrow_id: u64, hashlock: BytesN<32>) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_hashlock(env, transaction_id, hashlock)
+    }
+
+    /// Locks an escrow behind the SHA-256 hash of a secret known to the buyer.
+    ///
+    /// Both parties must authorize the hashlock. It can only be set once, and
+    /// only before the escrow is funded.
+    pub fn set_hashlock(env: Env, transaction_id: Symbol, hashlock: BytesN<32>) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        if Self::get_hashlock(env.clone(), transaction_id.clone()).is_some() {
+            return Err(EscrowError::HashlockAlreadySet);
+        }
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        let lock = HashLock {
+            hashlock,
+            preimage: None,
+        };
+        env.storage().persistent().set(&("hashlock", &transaction_id), &lock);
+        Utils::extend_escrow_ttl(&env, &("hashlock", &transaction_id), transaction.deadline);
+
+        Ok(())
+    }
+
+    /// Releases the funds for the hashlock's preimage by contract-assigned escrow ID.
+    pub fn claim_with_preimage_by_id(env: Env, escrow_id: u64, preimage: Bytes) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::claim_with_preimage(env, transaction_id, preimage)
+    }
+
+    /// Releases the funds to the seller in exchange for the hashlock's preimage.
+    ///
+    /// The revealed preimage is published in an `htlc_claimed` event so the
+    /// other chain's leg can be claimed with it.
+    pub fn claim_with_preimage(env: Env, transaction_id: Symbol, preimage: Bytes) -> Result<(), EscrowError> {
+        let mut lock = Self::get_hashlock(env.clone(), transaction_id.clone()).ok_or(EscrowError::HashlockNotSet)?;
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if Utils::is_past_deadline(&env, transaction.deadline) {
+            return Err(EscrowError::DeadlineExceeded);
+        }
+        if env.crypto().sha256(&preimage) != lock.hashlock {
+            return Err(EscrowError::InvalidPreimage);
+        }
+
+        // The preimage is the release condition, and claims are not paused.
+        FundManagementModule::release_to_seller(&env, &transaction)?;
+
+        lock.preimage = Some(preimage.clone());
+        env.storage().persistent().set(&("hashlock", &transaction_id), &lock);
+
+        env.events()
+            .publish((Symbol::new(&env, "htlc_claimed"), transaction_id.clone()), preimage);
+
+        // Record the claim action for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "claim_with_preimage");
+
+        Ok(())
+    }
+
+    /// Retrieves the hashlock guarding an escrow by contract-assigned escrow ID.
+    pub fn get_hashlock_by_id(env: Env, escrow_id: u64) -> Option<HashLock> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_hashlock(env, transaction_id)
+    }
+
+    /// Retrieves the hashlock guarding an escrow, if any.
+    pub fn get_hashlock(env: Env, transaction_id: Symbol) -> Option<HashLock> {
+        env.storage().persistent().get(&("hashlock", &transaction_id))
+    }
+}
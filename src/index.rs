@@ -0,0 +1,275 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::types::{EscrowPage, PartyRole, TransactionState};
+use crate::utils::Utils;
+
+/// Maximum number of IDs returned by a single listing query.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of IDs held in one bucket of an index.
+pub const BUCKET_SIZE: u32 = 100;
+
+/// Module for indexing escrows by party and by state.
+///
+/// Each index is split into buckets of `BUCKET_SIZE` slots, and cursors are
+/// slot offsets across them. Every bucket but the last is kept full, so a page
+/// reads at most two buckets. Entries leave the state index as escrows move
+/// between states, with the last entry taking the freed slot, so a page may
+/// skip or repeat an entry that changed state between calls.
+pub struct IndexModule;
+
+#[contractimpl]
+impl IndexModule {
+    /// Lists the escrows an address takes part in with the given role,
+    /// optionally restricted to a single state.
+    ///
+    /// A page examines at most `BUCKET_SIZE` entries, so a filtered page can
+    /// come back short with a `next_cursor` to continue from.
+    pub fn list_escrows_by_party(
+        env: Env,
+        address: Address,
+        role: PartyRole,
+        state_filter: Option<TransactionState>,
+        cursor: u32,
+        limit: u32,
+    ) -> EscrowPage {
+        Self::paginate(
+            &env,
+            &("party_buckets", &address, &role),
+            |bucket| ("party_escrows", &address, &role, bucket),
+            state_filter,
+            cursor,
+            limit,
+        )
+    }
+
+    /// Lists the escrows currently in the given state.
+    pub fn list_escrows_by_state(env: Env, state: TransactionState, cursor: u32, limit: u32) -> EscrowPage {
+        Self::paginate(
+            &env,
+            &("state_buckets", &state),
+            |bucket| ("state_escrows", &state, bucket),
+            None,
+            cursor,
+            limit,
+        )
+    }
+
+    /// Retrieves the indexed state of an escrow by contract-assigned escrow ID.
+    pub fn get_escrow_state_by_id(env: Env, escrow_id: u64) -> Option<TransactionState> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_escrow_state(env, transaction_id)
+    }
+
+    /// Retrieves the indexed state of an escrow.
+    pub fn get_escrow_state(env: Env, transaction_id: Symbol) -> Option<TransactionState> {
+        env.storage().persistent().get(&("escrow_state", &transaction_id))
+    }
+}
+
+impl IndexModule {
+    /// Adds a newly created escrow to both parties' indexes and the state index.
+    pub fn add_escrow(env: &Env, transaction_id: &Symbol, buyer: &Address, seller: &Address, state: TransactionState) {
+        Self::add_party_escrow(env, transaction_id, buyer, seller);
+        Self::push_state(env, transaction_id, &state);
+    }
+
+    /// Adds an escrow that has no transaction state, such as a subscription,
+    /// to both parties' indexes.
+    pub fn add_party_escrow(env: &Env, transaction_id: &Symbol, buyer: &Address, seller: &Address) {
+        let buyer_bucket = Self::push(
+            env,
+            &("party_buckets", buyer, &PartyRole::Buyer),
+            |bucket| ("party_escrows", buyer, &PartyRole::Buyer, bucket),
+            transaction_id,
+        );
+        let seller_bucket = Self::push(
+            env,
+            &("party_buckets", seller, &PartyRole::Seller),
+            |bucket| ("party_escrows", seller, &PartyRole::Seller, bucket),
+            transaction_id,
+        );
+
+        let key = ("party_bucket", transaction_id);
+        env.storage().persistent().set(&key, &(buyer_bucket, seller_bucket));
+        Utils::extend_shared_ttl(env, &key);
+    }
+
+    /// Moves an escrow to a new state in the state index.
+    pub fn set_state(env: &Env, transaction_id: &Symbol, state: TransactionState) {
+        if let Some(previous) = Self::get_escrow_state(env.clone(), transaction_id.clone()) {
+            Self::remove_state(env, transaction_id, &previous);
+        }
+
+        Self::push_state(env, transaction_id, &state);
+    }
+
+    /// Extends the index records that list an escrow, so they live as long as
+    /// the escrow's own records.
+    pub fn extend_ttl(env: &Env, transaction_id: &Symbol, buyer: &Address, seller: &Address) {
+        let storage = env.storage().persistent();
+        if let Some((buyer_bucket, seller_bucket)) = storage.get::<_, (u32, u32)>(&("party_bucket", transaction_id)) {
+            Utils::extend_shared_ttl(env, &("party_bucket", transaction_id));
+            Utils::extend_shared_ttl(env, &("party_buckets", buyer, &PartyRole::Buyer));
+            Utils::extend_shared_ttl(env, &("party_escrows", buyer, &PartyRole::Buyer, buyer_bucket));
+            Utils::extend_shared_ttl(env, &("party_buckets", seller, &PartyRole::Seller));
+            Utils::extend_shared_ttl(env, &("party_escrows", seller, &PartyRole::Seller, seller_bucket));
+        }
+
+        let Some(state) = Self::get_escrow_state(env.clone(), transaction_id.clone()) else {
+            return;
+        };
+        let bucket: u32 = storage.get(&("state_bucket", transaction_id)).unwrap_or(0);
+        Utils::extend_shared_ttl(env, &("escrow_state", transaction_id));
+        Utils::extend_shared_ttl(env, &("state_bucket", transaction_id));
+        Utils::extend_shared_ttl(env, &("state_buckets", &state));
+        Utils::extend_shared_ttl(env, &("state_escrows", &state, bucket));
+    }
+
+    /// Adds an ID to a state's index and records where it went.
+    fn push_state(env: &Env, transaction_id: &Symbol, state: &TransactionState) {
+        let bucket = Self::push(
+            env,
+            &("state_buckets", state),
+            |bucket| ("state_escrows", state, bucket),
+            transaction_id,
+        );
+
+        let storage = env.storage().persistent();
+        storage.set(&("state_bucket", transaction_id), &bucket);
+        Utils::extend_shared_ttl(env, &("state_bucket", transaction_id));
+        storage.set(&("escrow_state", transaction_id), state);
+        Utils::extend_shared_ttl(env, &("escrow_state", transaction_id));
+    }
+
+    /// Removes an ID from a state's index, moving the last ID in the index
+    /// into the freed slot so every bucket but the last stays full.
+    fn remove_state(env: &Env, transaction_id: &Symbol, state: &TransactionState) {
+        let storage = env.storage().persistent();
+        let count_key = ("state_buckets", state);
+        let buckets: u32 = storage.get(&count_key).unwrap_or(0);
+        if buckets == 0 {
+            return;
+        }
+
+        let bucket: u32 = storage.get(&("state_bucket", transaction_id)).unwrap_or(0);
+        let key = ("state_escrows", state, bucket);
+        let mut ids: Vec<Symbol> = storage.get(&key).unwrap_or(Vec::new(env));
+        let Some(position) = ids.first_index_of(transaction_id) else {
+            return;
+        };
+
+        let last_bucket = buckets - 1;
+        let last_key = ("state_escrows", state, last_bucket);
+        let mut last_ids: Vec<Symbol> = storage.get(&last_key).unwrap_or(Vec::new(env));
+        let Some(moved) = last_ids.pop_back() else {
+            return;
+        };
+
+        if &moved != transaction_id {
+            if bucket == last_bucket {
+                last_ids.set(position, moved.clone());
+            } else {
+                ids.set(position, moved.clone());
+                storage.set(&key, &ids);
+                Utils::extend_shared_ttl(env, &key);
+            }
+            storage.set(&("state_bucket", &moved), &bucket);
+            Utils::extend_shared_ttl(env, &("state_bucket", &moved));
+        }
+
+        if last_ids.is_empty() {
+            storage.remove(&last_key);
+            storage.set(&count_key, &last_bucket);
+            Utils::extend_shared_ttl(env, &count_key);
+        } else {
+            storage.set(&last_key, &last_ids);
+            Utils::extend_shared_ttl(env, &last_key);
+        }
+    }
+
+    /// Appends an ID to the last bucket of an index, opening a new bucket once
+    /// the last one is full, and returns the bucket it went into.
+    fn push<C, K>(env: &Env, count_key: &C, bucket_key: impl Fn(u32) -> K, transaction_id: &Symbol) -> u32
+    where
+        C: IntoVal<Env, Val>,
+        K: IntoVal<Env, Val>,
+    {
+        let storage = env.storage().persistent();
+        let mut buckets: u32 = storage.get(count_key).unwrap_or(0);
+
+        let mut bucket = buckets.saturating_sub(1);
+        let mut ids: Vec<Symbol> = storage.get(&bucket_key(bucket)).unwrap_or(Vec::new(env));
+        if buckets == 0 || ids.len() >= BUCKET_SIZE {
+            bucket = buckets;
+            buckets += 1;
+            ids = Vec::new(env);
+            storage.set(count_key, &buckets);
+            Utils::extend_shared_ttl(env, count_key);
+        }
+
+        ids.push_back(transaction_id.clone());
+        let key = bucket_key(bucket);
+        storage.set(&key, &ids);
+        Utils::extend_shared_ttl(env, &key);
+        bucket
+    }
+
+    /// Collects up to `limit` IDs of an index starting at slot `cursor`,
+    /// skipping those that do not match `state_filter`. At most `BUCKET_SIZE`
+    /// slots are examined.
+    fn paginate<C, K>(
+        env: &Env,
+        count_key: &C,
+        bucket_key: impl Fn(u32) -> K,
+        state_filter: Option<TransactionState>,
+        cursor: u32,
+        limit: u32,
+    ) -> EscrowPage
+    where
+        C: IntoVal<Env, Val>,
+        K: IntoVal<Env, Val>,
+    {
+        let storage = env.storage().persistent();
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let buckets: u32 = storage.get(count_key).unwrap_or(0);
+
+        // Only the last bucket may be partly filled.
+        let total = match buckets {
+            0 => 0,
+            _ => {
+                let last: Vec<Symbol> = storage.get(&bucket_key(buckets - 1)).unwrap_or(Vec::new(env));
+                (buckets - 1) * BUCKET_SIZE + last.len()
+            }
+        };
+
+        let mut page = Vec::new(env);
+        let mut position = cursor;
+        let end = total.min(cursor.saturating_add(BUCKET_SIZE));
+        let mut loaded: Option<(u32, Vec<Symbol>)> = None;
+
+        while position < end && page.len() < limit {
+            let bucket = position / BUCKET_SIZE;
+            if !matches!(&loaded, Some((index, _)) if *index == bucket) {
+                loaded = Some((bucket, storage.get(&bucket_key(bucket)).unwrap_or(Vec::new(env))));
+            }
+            let transaction_id = loaded.as_ref().unwrap().1.get_unchecked(position % BUCKET_SIZE);
+            position += 1;
+
+            let matches = match &state_filter {
+                Some(state) => Self::get_escrow_state(env.clone(), transaction_id.clone()).as_ref() == Some(state),
+                None => true,
+            };
+            if matches {
+                page.push_back(transaction_id);
+            }
+        }
+
+        EscrowPage {
+            ids: page,
+            next_cursor: if position < total { Some(position) } else { None },
+        }
+    }
+}
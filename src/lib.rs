@@ -9,12 +9,47 @@ mod audit;
 mod types;
 mod errors;
 mod utils;
+mod limits;
+mod pause;
+mod upgrade;
+mod access_control;
+mod approvals;
+mod index;
+mod tokens;
+mod accounting;
+mod batch;
+mod subscription;
+mod oracle;
+mod htlc;
+mod payees;
+mod basket;
+mod swap;
+mod bonds;
+mod arbitrators;
 
 use agreement::AgreementModule;
 use fund_management::FundManagementModule;
 use dispute_resolution::DisputeResolutionModule;
 use audit::AuditModule;
+use limits::LimitsModule;
+use pause::PauseModule;
+use access_control::AccessControlModule;
+use approvals::ApprovalModule;
+use index::IndexModule;
+use tokens::TokenModule;
+use accounting::AccountingModule;
+use batch::BatchModule;
+use subscription::SubscriptionModule;
+use oracle::OracleModule;
+use htlc::HtlcModule;
+use payees::PayeeModule;
+use basket::BasketModule;
+use swap::SwapModule;
+use bonds::BondModule;
+use arbitrators::ArbitratorModule;
+use upgrade::{UpgradeModule, CURRENT_SCHEMA_VERSION};
 use types::{Agreement, Transaction, Dispute};
+use utils::Utils;
 use errors::EscrowError;
 
 /// Main contract struct for the decentralized escrow service.
@@ -24,16 +59,25 @@ pub struct DecentralizedEscrowService;
 /// Implementation of the main contract.
 #[contractimpl]
 impl DecentralizedEscrowService {
-    /// Initializes the contract.
-    pub fn initialize(env: Env) {
-        // Initialization logic, if any, goes here.
-        // This could include setting up initial state or configuration.
+    /// Initializes the contract with the admin allowed to manage configuration.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), EscrowError> {
+        // The contract can only be initialized once.
+        if env.storage().instance().has(&"admin") {
+            return Err(EscrowError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&"admin", &admin);
+        env.storage().instance().set(&"schema_version", &CURRENT_SCHEMA_VERSION);
+        Utils::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Executes a transaction lifecycle from setup to completion.
     pub fn execute_transaction(env: Env, transaction_id: Symbol) -> Result<(), EscrowError> {
         // Retrieve the transaction details from storage.
-        let transaction: Transaction = env.storage().get(&transaction_id).ok_or(EscrowError::TransactionNotFound)?;
+        let transaction: Transaction = Utils::load_transaction(&env, &transaction_id)?;
 
         // Depending on the transaction state, perform the appropriate action.
         match transaction.state {
@@ -43,8 +87,10 @@ impl DecentralizedEscrowService {
             }
             // If the transaction is in the deposit state, check for fulfillment or disputes.
             TransactionState::Deposit => {
-                if transaction.is_fulfilled() {
+                if transaction.is_fulfilled(&env) {
                     FundManagementModule::release_funds(&env, &transaction)?;
+                } else if transaction.is_failed(&env) {
+                    FundManagementModule::refund_funds(&env, &transaction);
                 } else if transaction.has_dispute() {
                     DisputeResolutionModule::resolve_dispute(&env, &transaction)?;
                 }
@@ -0,0 +1,107 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::types::{Exposure, ExposureCaps, TokenLimits};
+use crate::errors::EscrowError;
+use crate::utils::Utils;
+
+/// Module for enforcing trade limits and per-address exposure caps.
+pub struct LimitsModule;
+
+#[contractimpl]
+impl LimitsModule {
+    /// Sets the minimum and maximum escrow amount for a token.
+    pub fn set_token_limits(env: Env, token: Address, limits: TokenLimits) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        if limits.min_amount > limits.max_amount {
+            return Err(EscrowError::InvalidAmount);
+        }
+        env.storage().instance().set(&("token_limits", &token), &limits);
+        Ok(())
+    }
+
+    /// Sets the caps on open escrows applied to every address.
+    pub fn set_exposure_caps(env: Env, caps: ExposureCaps) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"exposure_caps", &caps);
+        Ok(())
+    }
+
+    /// Retrieves the current exposure of an address.
+    pub fn get_exposure(env: Env, address: Address) -> Exposure {
+        env.storage().persistent().get(&("exposure", &address)).unwrap_or_default()
+    }
+}
+
+impl LimitsModule {
+    /// Validates a new escrow against the token limits and both parties' caps,
+    /// then records it as open exposure for the buyer and the seller.
+    pub fn open_escrow(
+        env: &Env,
+        buyer: &Address,
+        seller: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        // An address trading with itself would count its exposure twice and
+        // could inflate its own completed-trade bonus.
+        if buyer == seller {
+            return Err(EscrowError::SelfEscrow);
+        }
+
+        // Enforce the per-token amount range, if one is configured.
+        if let Some(limits) = env.storage().instance().get::<_, TokenLimits>(&("token_limits", token)) {
+            if amount < limits.min_amount {
+                return Err(EscrowError::AmountBelowMinimum);
+            }
+            if amount > limits.max_amount {
+                return Err(EscrowError::AmountAboveMaximum);
+            }
+        }
+
+        let caps: Option<ExposureCaps> = env.storage().instance().get(&"exposure_caps");
+        for party in [buyer, seller] {
+            let mut exposure = Self::get_exposure(env.clone(), party.clone());
+
+            // Enforce the per-address caps, if configured.
+            if let Some(caps) = &caps {
+                if exposure.open_escrows >= caps.max_open_escrows {
+                    return Err(EscrowError::OpenEscrowLimitExceeded);
+                }
+                let bonus = caps
+                    .value_bonus_per_completed
+                    .checked_mul(exposure.completed as i128)
+                    .unwrap_or(i128::MAX)
+                    .min(caps.max_value_bonus);
+                let max_value = caps.max_open_value.checked_add(bonus).unwrap_or(i128::MAX);
+                if exposure.open_value.checked_add(amount).ok_or(EscrowError::AmountOverflow)? > max_value {
+                    return Err(EscrowError::OpenValueLimitExceeded);
+                }
+            }
+
+            exposure.open_escrows += 1;
+            exposure.open_value = exposure.open_value.checked_add(amount).ok_or(EscrowError::AmountOverflow)?;
+            env.storage().persistent().set(&("exposure", party), &exposure);
+            Utils::extend_shared_ttl(env, &("exposure", party));
+        }
+
+        Ok(())
+    }
+
+    /// Removes a closed escrow from both parties' exposure.
+    ///
+    /// Completed escrows count toward each party's reputation bonus.
+    pub fn close_escrow(env: &Env, buyer: &Address, seller: &Address, amount: i128, completed: bool) {
+        for party in [buyer, seller] {
+            let mut exposure = Self::get_exposure(env.clone(), party.clone());
+            exposure.open_escrows = exposure.open_escrows.saturating_sub(1);
+            exposure.open_value = (exposure.open_value - amount).max(0);
+            if completed {
+                exposure.completed += 1;
+            }
+            env.storage().persistent().set(&("exposure", party), &exposure);
+            Utils::extend_shared_ttl(env, &("exposure", party));
+        }
+    }
+}
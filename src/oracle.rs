@@ -0,0 +1,117 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::types::{AttestationOutcome, OracleCondition, TransactionState};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::fund_management::FundManagementModule;
+use crate::utils::Utils;
+
+/// Module for escrows whose release is decided by an oracle.
+pub struct OracleModule;
+
+#[contractimpl]
+impl OracleModule {
+    /// Designates the oracle that decides an escrow by contract-assigned escrow ID.
+    pub fn set_oracle_by_id(
+        env: Env,
+        escrow_id: u64,
+        oracle: Address,
+        condition_id: Symbol,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_oracle(env, transaction_id, oracle, condition_id)
+    }
+
+    /// Designates the oracle and condition that decide an escrow.
+    ///
+    /// Both parties must authorize it, and it can only be set before the
+    /// funds are released.
+    pub fn set_oracle(
+        env: Env,
+        transaction_id: Symbol,
+        oracle: Address,
+        condition_id: Symbol,
+    ) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup && transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        let condition = OracleCondition {
+            oracle,
+            condition_id,
+            outcome: None,
+        };
+        env.storage().persistent().set(&("oracle", &transaction_id), &condition);
+        Utils::extend_escrow_ttl(&env, &("oracle", &transaction_id), transaction.deadline);
+
+        Ok(())
+    }
+
+    /// Records the oracle's outcome by contract-assigned escrow ID.
+    pub fn attest_by_id(
+        env: Env,
+        escrow_id: u64,
+        oracle: Address,
+        outcome: AttestationOutcome,
+    ) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::attest(env, transaction_id, oracle, outcome)
+    }
+
+    /// Records the designated oracle's outcome for an escrow.
+    ///
+    /// If the escrow is funded, a `Fulfilled` outcome releases the funds to
+    /// the seller and a `Failed` outcome refunds the buyer. Otherwise the
+    /// outcome is kept and applied through `execute_transaction`.
+    pub fn attest(
+        env: Env,
+        transaction_id: Symbol,
+        oracle: Address,
+        outcome: AttestationOutcome,
+    ) -> Result<(), EscrowError> {
+        let mut condition = Self::get_oracle_condition(env.clone(), transaction_id.clone()).ok_or(EscrowError::OracleNotSet)?;
+
+        if condition.oracle != oracle {
+            return Err(EscrowError::Unauthorized);
+        }
+        if condition.outcome.is_some() {
+            return Err(EscrowError::AlreadyAttested);
+        }
+
+        oracle.require_auth();
+
+        condition.outcome = Some(outcome.clone());
+        env.storage().persistent().set(&("oracle", &transaction_id), &condition);
+
+        // Record the attestation for audit purposes.
+        AuditModule::record_action(&env, &transaction_id, "attest");
+
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+        if transaction.state == TransactionState::Deposit {
+            match outcome {
+                AttestationOutcome::Fulfilled => FundManagementModule::release_funds(env.clone(), &transaction)?,
+                AttestationOutcome::Failed => FundManagementModule::refund_funds(&env, &transaction),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the oracle condition by contract-assigned escrow ID.
+    pub fn get_oracle_condition_by_id(env: Env, escrow_id: u64) -> Option<OracleCondition> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_oracle_condition(env, transaction_id)
+    }
+
+    /// Retrieves the oracle condition attached to an escrow, if any.
+    pub fn get_oracle_condition(env: Env, transaction_id: Symbol) -> Option<OracleCondition> {
+        env.storage().persistent().get(&("oracle", &transaction_id))
+    }
+}
@@ -0,0 +1,53 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::types::{Operation, PauseScope, Role};
+use crate::errors::EscrowError;
+use crate::access_control::AccessControlModule;
+
+/// Module for pausing escrow operations in an emergency.
+///
+/// Refunds, dispute resolution and hashlock claims are never gated by a
+/// pause, so funds already in escrow can always leave the contract and a
+/// pause cannot decide who wins a timelocked swap.
+pub struct PauseModule;
+
+#[contractimpl]
+impl PauseModule {
+    /// Pauses the given scope.
+    pub fn pause(env: Env, caller: Address, scope: PauseScope) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::Pauser)?;
+        env.storage().instance().set(&("paused", &scope), &true);
+        Ok(())
+    }
+
+    /// Lifts a pause on the given scope.
+    pub fn unpause(env: Env, caller: Address, scope: PauseScope) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::Pauser)?;
+        env.storage().instance().remove(&("paused", &scope));
+        Ok(())
+    }
+
+    /// Checks whether the given scope is paused.
+    pub fn is_paused(env: Env, scope: PauseScope) -> bool {
+        env.storage().instance().get(&("paused", &scope)).unwrap_or(false)
+    }
+}
+
+impl PauseModule {
+    /// Fails if the operation is paused globally, for the token, or on its own.
+    pub fn ensure_not_paused(env: &Env, operation: Operation, token: &Address) -> Result<(), EscrowError> {
+        let scopes = [
+            PauseScope::Global,
+            PauseScope::Token(token.clone()),
+            PauseScope::Operation(operation),
+        ];
+        for scope in scopes {
+            if Self::is_paused(env.clone(), scope) {
+                return Err(EscrowError::ContractPaused);
+            }
+        }
+        Ok(())
+    }
+}
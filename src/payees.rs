@@ -0,0 +1,109 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol, Vec};
+
+use crate::types::{Payee, PayeeSplit, TransactionState};
+use crate::errors::EscrowError;
+use crate::utils::Utils;
+
+/// Basis points making up a whole.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Module for escrows that pay out to several weighted payees.
+pub struct PayeeModule;
+
+#[contractimpl]
+impl PayeeModule {
+    /// Splits an escrow's release across payees by contract-assigned escrow ID.
+    pub fn set_payees_by_id(env: Env, escrow_id: u64, payees: Vec<Payee>) -> Result<(), EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::set_payees(env, transaction_id, payees)
+    }
+
+    /// Splits an escrow's release across payees by basis-point share.
+    ///
+    /// Shares must total 10000. Both parties must authorize the split, and it
+    /// can only be set before the funds are released.
+    pub fn set_payees(env: Env, transaction_id: Symbol, payees: Vec<Payee>) -> Result<(), EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id)?;
+
+        if transaction.state != TransactionState::Setup && transaction.state != TransactionState::Deposit {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        Self::validate_shares(&payees)?;
+
+        transaction.buyer.require_auth();
+        transaction.seller.require_auth();
+
+        // Record the rounding remainder up front so the payout is fully determined.
+        let distributed: i128 = payees
+            .iter()
+            .map(|payee| Self::share_of(transaction.amount, payee.share_bps))
+            .sum();
+        let split = PayeeSplit {
+            payees,
+            dust: transaction.amount - distributed,
+        };
+        env.storage().persistent().set(&("payees", &transaction_id), &split);
+        Utils::extend_escrow_ttl(&env, &("payees", &transaction_id), transaction.deadline);
+
+        Ok(())
+    }
+
+    /// Retrieves the payee split by contract-assigned escrow ID.
+    pub fn get_payees_by_id(env: Env, escrow_id: u64) -> Option<PayeeSplit> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_payees(env, transaction_id)
+    }
+
+    /// Retrieves the payee split attached to an escrow, if any.
+    pub fn get_payees(env: Env, transaction_id: Symbol) -> Option<PayeeSplit> {
+        env.storage().persistent().get(&("payees", &transaction_id))
+    }
+}
+
+impl PayeeModule {
+    /// Transfers `amount` from the contract to the payees, paying the dust to the first payee.
+    ///
+    /// The dust is recomputed for `amount`, so partial payouts split the same way.
+    pub fn transfer_split(env: &Env, token: &Address, split: &PayeeSplit, amount: i128) {
+        let distributed: i128 = split
+            .payees
+            .iter()
+            .map(|payee| Self::share_of(amount, payee.share_bps))
+            .sum();
+        let dust = amount - distributed;
+
+        let token_client = token::Client::new(env, token);
+        for (index, payee) in split.payees.iter().enumerate() {
+            let mut payout = Self::share_of(amount, payee.share_bps);
+            if index == 0 {
+                payout += dust;
+            }
+            if payout > 0 {
+                token_client.transfer(&env.current_contract_address(), &payee.address, &payout);
+            }
+        }
+    }
+
+    /// Fails unless there is at least one payee and the shares total exactly
+    /// `BPS_DENOMINATOR` without overflowing.
+    fn validate_shares(payees: &Vec<Payee>) -> Result<(), EscrowError> {
+        let mut total: u32 = 0;
+        for payee in payees.iter() {
+            if payee.share_bps > BPS_DENOMINATOR {
+                return Err(EscrowError::InvalidShares);
+            }
+            total = total.checked_add(payee.share_bps).ok_or(EscrowError::InvalidShares)?;
+        }
+        if payees.is_empty() || total != BPS_DENOMINATOR {
+            return Err(EscrowError::InvalidShares);
+        }
+        Ok(())
+    }
+
+    /// Share of `amount` for `share_bps`, rounded down.
+    fn share_of(amount: i128, share_bps: u32) -> i128 {
+        amount * share_bps as i128 / BPS_DENOMINATOR as i128
+    }
+}
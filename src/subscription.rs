@@ -0,0 +1,345 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol, Vec};
+
+use crate::types::{ClaimedRemedy, Dispute, DisputeReason, DisputeState, InstallmentState, Operation, Subscription};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::accounting::AccountingModule;
+use crate::dispute_resolution::DisputeResolutionModule;
+use crate::index::IndexModule;
+use crate::limits::LimitsModule;
+use crate::pause::PauseModule;
+use crate::payees::BPS_DENOMINATOR;
+use crate::tokens::TokenModule;
+use crate::utils::Utils;
+
+/// Module for recurring escrows paid out in installments.
+///
+/// The buyer funds every period up front. Each installment becomes releasable
+/// to the seller once its period ends, can be disputed on its own, and is
+/// refunded to the buyer if the subscription is cancelled before it ends.
+pub struct SubscriptionModule;
+
+#[contractimpl]
+impl SubscriptionModule {
+    /// Creates a subscription and deposits `installment_amount * periods` from the buyer.
+    ///
+    /// Subscriptions share their IDs with escrows, so IDs starting with `esc_`
+    /// are reserved for contract-assigned escrows here too.
+    pub fn create_subscription(
+        env: Env,
+        subscription_id: Symbol,
+        buyer: Address,
+        seller: Address,
+        token: Address,
+        installment_amount: i128,
+        period: u64,
+        periods: u32,
+    ) -> Result<(), EscrowError> {
+        if Utils::is_reserved_id(&env, &subscription_id) {
+            return Err(EscrowError::ReservedTransactionId);
+        }
+
+        // Ensure the subscription ID is unique, including among escrows, which share the party index.
+        if env.storage().persistent().has(&("subscription", &subscription_id))
+            || env.storage().persistent().has(&subscription_id)
+        {
+            return Err(EscrowError::AgreementAlreadyExists);
+        }
+        if period == 0 || periods == 0 {
+            return Err(EscrowError::InvalidInstallment);
+        }
+
+        PauseModule::ensure_not_paused(&env, Operation::Creation, &token)?;
+        PauseModule::ensure_not_paused(&env, Operation::Funding, &token)?;
+        TokenModule::validate(&env, &token, installment_amount)?;
+
+        let total = installment_amount
+            .checked_mul(periods as i128)
+            .ok_or(EscrowError::AmountOverflow)?;
+
+        // Both parties must agree before the subscription counts against their limits.
+        buyer.require_auth();
+        seller.require_auth();
+
+        // Enforce trade limits on the full amount and make the subscription discoverable by party.
+        LimitsModule::open_escrow(&env, &buyer, &seller, &token, total)?;
+        IndexModule::add_party_escrow(&env, &subscription_id, &buyer, &seller);
+
+        // Compute the end of every period from the current ledger time.
+        let mut period_ends = Vec::new(&env);
+        let mut installments = Vec::new(&env);
+        for index in 1..=periods as u64 {
+            let duration = period.checked_mul(index).ok_or(EscrowError::AmountOverflow)?;
+            period_ends.push_back(Utils::calculate_deadline(&env, duration));
+            installments.push_back(InstallmentState::Pending);
+        }
+
+        // Transfer the full amount from the buyer to the escrow contract.
+        token::Client::new(&env, &token).transfer(&buyer, &env.current_contract_address(), &total);
+        AccountingModule::add_escrowed(&env, &token, total);
+
+        let subscription = Subscription {
+            id: subscription_id.clone(),
+            buyer,
+            seller,
+            token,
+            installment_amount,
+            period_ends,
+            installments,
+            cancelled: false,
+        };
+        Self::save(&env, &subscription);
+
+        // Record the creation action for audit purposes.
+        AuditModule::record_action(&env, &subscription_id, "create_subscription");
+
+        Ok(())
+    }
+
+    /// Releases an installment to the seller once its period has ended. Anyone may call this.
+    pub fn release_installment(env: Env, subscription_id: Symbol, index: u32) -> Result<(), EscrowError> {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        Self::ensure_pending(&subscription, index)?;
+
+        if !Utils::is_past_deadline(&env, subscription.period_ends.get_unchecked(index)) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
+        PauseModule::ensure_not_paused(&env, Operation::Release, &subscription.token)?;
+
+        Self::pay_installment(&env, &mut subscription, index, BPS_DENOMINATOR);
+        AuditModule::record_action(&env, &subscription_id, "release_installment");
+
+        Ok(())
+    }
+
+    /// Cancels a subscription, refunding every installment whose period has not ended.
+    ///
+    /// Installments for ended periods stay releasable to the seller, and
+    /// disputed installments await resolution.
+    pub fn cancel_subscription(env: Env, subscription_id: Symbol) -> Result<(), EscrowError> {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.cancelled {
+            return Err(EscrowError::SubscriptionCancelled);
+        }
+
+        subscription.buyer.require_auth();
+
+        for index in 0..subscription.installments.len() {
+            let pending = subscription.installments.get_unchecked(index) == InstallmentState::Pending;
+            let unused = !Utils::is_past_deadline(&env, subscription.period_ends.get_unchecked(index));
+            if pending && unused {
+                Self::pay_installment(&env, &mut subscription, index, 0);
+            }
+        }
+
+        subscription.cancelled = true;
+        Self::save(&env, &subscription);
+        AuditModule::record_action(&env, &subscription_id, "cancel_subscription");
+
+        Ok(())
+    }
+
+    /// Raises a dispute on a single installment, stating why and what the raiser claims.
+    ///
+    /// The dispute goes to a panel and takes a bond like an escrow dispute,
+    /// with the installment amount standing in for the escrow amount.
+    pub fn dispute_installment(
+        env: Env,
+        subscription_id: Symbol,
+        index: u32,
+        raiser: Address,
+        reason: DisputeReason,
+        remedy: ClaimedRemedy,
+    ) -> Result<(), EscrowError> {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        Self::ensure_pending(&subscription, index)?;
+
+        if raiser != subscription.buyer && raiser != subscription.seller {
+            return Err(EscrowError::Unauthorized);
+        }
+        raiser.require_auth();
+
+        let dispute = DisputeResolutionModule::open(
+            &env,
+            &subscription_id,
+            &subscription.token,
+            subscription.installment_amount,
+            &subscription.buyer,
+            &subscription.seller,
+            raiser,
+            reason,
+            remedy,
+        )?;
+        Self::save_dispute(&env, &subscription, index, &dispute);
+
+        subscription.installments.set(index, InstallmentState::Disputed);
+        Self::save(&env, &subscription);
+        AuditModule::record_action(&env, &subscription_id, "dispute_installment");
+
+        Ok(())
+    }
+
+    /// Casts a panel member's vote on a disputed installment, paying it to
+    /// the seller or refunding the buyer once a majority of the panel agrees.
+    pub fn resolve_installment(
+        env: Env,
+        subscription_id: Symbol,
+        index: u32,
+        arbitrator: Address,
+        release_to_seller: bool,
+    ) -> Result<(), EscrowError> {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        let mut dispute = Self::get_installment_dispute(env.clone(), subscription_id.clone(), index)?;
+
+        if !DisputeResolutionModule::cast_vote(&mut dispute, &arbitrator, release_to_seller)? {
+            Self::save_dispute(&env, &subscription, index, &dispute);
+            AuditModule::record_action(&env, &subscription_id, "vote_installment");
+            return Ok(());
+        }
+
+        Self::rule_installment(&env, &mut subscription, index, &mut dispute, release_to_seller, &arbitrator);
+        AuditModule::record_action(&env, &subscription_id, "resolve_installment");
+
+        Ok(())
+    }
+
+    /// Ends an installment dispute the panel has not decided within the
+    /// ruling timeout. Anyone may call this.
+    ///
+    /// The side with more votes wins. A tie, including no votes at all,
+    /// splits the installment evenly and returns the dispute bond.
+    pub fn rule_installment_on_timeout(env: Env, subscription_id: Symbol, index: u32) -> Result<(), EscrowError> {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        let mut dispute = Self::get_installment_dispute(env.clone(), subscription_id.clone(), index)?;
+
+        match DisputeResolutionModule::timed_out_ruling(&env, &dispute)? {
+            Some((release_to_seller, decider)) => {
+                Self::rule_installment(&env, &mut subscription, index, &mut dispute, release_to_seller, &decider);
+            }
+            None => {
+                let seller_bps = BPS_DENOMINATOR / 2;
+                Self::pay_installment(&env, &mut subscription, index, seller_bps);
+                let raiser = dispute.raiser.clone();
+                DisputeResolutionModule::close(
+                    &env,
+                    &mut dispute,
+                    &subscription.token,
+                    &raiser,
+                    DisputeState::TimedOut,
+                    Some(seller_bps),
+                );
+                Self::save_dispute(&env, &subscription, index, &dispute);
+            }
+        }
+
+        AuditModule::record_action(&env, &subscription_id, "rule_installment_on_timeout");
+
+        Ok(())
+    }
+
+    /// Retrieves the dispute raised on an installment.
+    pub fn get_installment_dispute(env: Env, subscription_id: Symbol, index: u32) -> Result<Dispute, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&("installment_dispute", &subscription_id, index))
+            .ok_or(EscrowError::DisputeNotFound)
+    }
+
+    /// Retrieves a subscription by ID.
+    pub fn get_subscription(env: Env, subscription_id: Symbol) -> Result<Subscription, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&("subscription", &subscription_id))
+            .ok_or(EscrowError::SubscriptionNotFound)
+    }
+}
+
+impl SubscriptionModule {
+    fn ensure_pending(subscription: &Subscription, index: u32) -> Result<(), EscrowError> {
+        if index >= subscription.installments.len() {
+            return Err(EscrowError::InvalidInstallment);
+        }
+        if subscription.installments.get_unchecked(index) != InstallmentState::Pending {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+        Ok(())
+    }
+
+    /// Carries out a panel ruling on an installment and stores the dispute.
+    fn rule_installment(
+        env: &Env,
+        subscription: &mut Subscription,
+        index: u32,
+        dispute: &mut Dispute,
+        release_to_seller: bool,
+        decider: &Address,
+    ) {
+        let (winner, seller_bps) = if release_to_seller {
+            (subscription.seller.clone(), BPS_DENOMINATOR)
+        } else {
+            (subscription.buyer.clone(), 0)
+        };
+        Self::pay_installment(env, subscription, index, seller_bps);
+
+        let bond_recipient = DisputeResolutionModule::ruling_bond_recipient(env, dispute, &winner, decider);
+        DisputeResolutionModule::close(
+            env,
+            dispute,
+            &subscription.token,
+            &bond_recipient,
+            DisputeState::Resolved,
+            Some(seller_bps),
+        );
+        Self::save_dispute(env, subscription, index, dispute);
+    }
+
+    /// Pays `seller_bps` of one installment to the seller and the rest back to
+    /// the buyer, and saves the result. The installment counts as released if
+    /// the seller gets anything.
+    fn pay_installment(env: &Env, subscription: &mut Subscription, index: u32, seller_bps: u32) {
+        let amount = subscription.installment_amount;
+        let seller_amount = amount * seller_bps as i128 / BPS_DENOMINATOR as i128;
+        let buyer_amount = amount - seller_amount;
+
+        let token_client = token::Client::new(env, &subscription.token);
+        if seller_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &subscription.seller, &seller_amount);
+        }
+        if buyer_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &subscription.buyer, &buyer_amount);
+        }
+        AccountingModule::add_escrowed(env, &subscription.token, -amount);
+
+        let state = if seller_bps > 0 { InstallmentState::Released } else { InstallmentState::Refunded };
+        subscription.installments.set(index, state);
+        Self::save(env, subscription);
+
+        // Once every installment is paid out, release the parties' exposure.
+        let settled = subscription
+            .installments
+            .iter()
+            .all(|installment| installment == InstallmentState::Released || installment == InstallmentState::Refunded);
+        if settled {
+            let total = subscription.installment_amount * subscription.installments.len() as i128;
+            let completed = subscription.installments.contains(&InstallmentState::Released);
+            LimitsModule::close_escrow(env, &subscription.buyer, &subscription.seller, total, completed);
+        }
+    }
+
+    /// Stores an installment's dispute, keeping it alive as long as the subscription.
+    fn save_dispute(env: &Env, subscription: &Subscription, index: u32, dispute: &Dispute) {
+        let key = ("installment_dispute", &subscription.id, index);
+        env.storage().persistent().set(&key, dispute);
+        Utils::extend_escrow_ttl(env, &key, subscription.period_ends.last().unwrap_or(0));
+    }
+
+    /// Stores a subscription, keeping it alive until its last period ends.
+    fn save(env: &Env, subscription: &Subscription) {
+        let key = ("subscription", &subscription.id);
+        env.storage().persistent().set(&key, subscription);
+        let last_end = subscription.period_ends.last().unwrap_or(0);
+        Utils::extend_escrow_ttl(env, &key, last_end);
+    }
+}
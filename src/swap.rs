@@ -0,0 +1,146 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol};
+
+use crate::types::{Operation, Swap, SwapLeg, SwapState};
+use crate::errors::EscrowError;
+use crate::audit::AuditModule;
+use crate::accounting::AccountingModule;
+use crate::pause::PauseModule;
+use crate::tokens::TokenModule;
+use crate::utils::Utils;
+
+/// Module for two-sided swap escrows.
+///
+/// The buyer deposits one token and the seller another, each before its own
+/// funding deadline. Once both legs are in, the contract exchanges them in
+/// the same call. If a side misses its deadline, `expire_swap` refunds the
+/// other side.
+pub struct SwapModule;
+
+#[contractimpl]
+impl SwapModule {
+    /// Creates a swap between the buyer's and the seller's legs.
+    pub fn create_swap(env: Env, swap_id: Symbol, buyer_leg: SwapLeg, seller_leg: SwapLeg) -> Result<(), EscrowError> {
+        // Ensure the swap ID is unique.
+        if env.storage().persistent().has(&("swap", &swap_id)) {
+            return Err(EscrowError::AgreementAlreadyExists);
+        }
+
+        for leg in [&buyer_leg, &seller_leg] {
+            if leg.funded {
+                return Err(EscrowError::AlreadyFunded);
+            }
+            PauseModule::ensure_not_paused(&env, Operation::Creation, &leg.token)?;
+            TokenModule::validate(&env, &leg.token, leg.amount)?;
+            leg.party.require_auth();
+        }
+
+        let swap = Swap {
+            id: swap_id.clone(),
+            buyer_leg,
+            seller_leg,
+            state: SwapState::Open,
+        };
+        Self::save(&env, &swap);
+
+        // Record the creation action for audit purposes.
+        AuditModule::record_action(&env, &swap_id, "create_swap");
+
+        Ok(())
+    }
+
+    /// Deposits a party's leg, completing the swap once both legs are in.
+    pub fn fund_swap(env: Env, swap_id: Symbol, party: Address) -> Result<(), EscrowError> {
+        let mut swap = Self::get_swap(env.clone(), swap_id.clone())?;
+        if swap.state != SwapState::Open {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+
+        let leg = if party == swap.buyer_leg.party {
+            &mut swap.buyer_leg
+        } else if party == swap.seller_leg.party {
+            &mut swap.seller_leg
+        } else {
+            return Err(EscrowError::Unauthorized);
+        };
+
+        if leg.funded {
+            return Err(EscrowError::AlreadyFunded);
+        }
+        if Utils::is_past_deadline(&env, leg.funding_deadline) {
+            return Err(EscrowError::DeadlineExceeded);
+        }
+        PauseModule::ensure_not_paused(&env, Operation::Funding, &leg.token)?;
+
+        party.require_auth();
+        token::Client::new(&env, &leg.token).transfer(&party, &env.current_contract_address(), &leg.amount);
+        AccountingModule::add_escrowed(&env, &leg.token, leg.amount);
+        leg.funded = true;
+
+        // Exchange the legs as soon as both are in.
+        if swap.buyer_leg.funded && swap.seller_leg.funded {
+            PauseModule::ensure_not_paused(&env, Operation::Release, &swap.buyer_leg.token)?;
+            PauseModule::ensure_not_paused(&env, Operation::Release, &swap.seller_leg.token)?;
+            Self::pay_leg(&env, &swap.buyer_leg, &swap.seller_leg.party);
+            Self::pay_leg(&env, &swap.seller_leg, &swap.buyer_leg.party);
+            swap.state = SwapState::Completed;
+        }
+
+        Self::save(&env, &swap);
+        AuditModule::record_action(&env, &swap_id, "fund_swap");
+
+        Ok(())
+    }
+
+    /// Refunds the funded side once the other side has missed its funding deadline.
+    /// Anyone may call this.
+    pub fn expire_swap(env: Env, swap_id: Symbol) -> Result<(), EscrowError> {
+        let mut swap = Self::get_swap(env.clone(), swap_id.clone())?;
+        if swap.state != SwapState::Open {
+            return Err(EscrowError::InvalidTransactionState);
+        }
+
+        let missed = |leg: &SwapLeg| !leg.funded && Utils::is_past_deadline(&env, leg.funding_deadline);
+        if !missed(&swap.buyer_leg) && !missed(&swap.seller_leg) {
+            return Err(EscrowError::DeadlineNotReached);
+        }
+
+        // Refunds are never paused, so funds cannot be trapped.
+        for leg in [&swap.buyer_leg, &swap.seller_leg] {
+            if leg.funded {
+                Self::pay_leg(&env, leg, &leg.party);
+            }
+        }
+
+        swap.state = SwapState::Expired;
+        Self::save(&env, &swap);
+        AuditModule::record_action(&env, &swap_id, "expire_swap");
+
+        Ok(())
+    }
+
+    /// Retrieves a swap by ID.
+    pub fn get_swap(env: Env, swap_id: Symbol) -> Result<Swap, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&("swap", &swap_id))
+            .ok_or(EscrowError::SwapNotFound)
+    }
+}
+
+impl SwapModule {
+    /// Transfers a deposited leg out of the contract.
+    fn pay_leg(env: &Env, leg: &SwapLeg, recipient: &Address) {
+        token::Client::new(env, &leg.token).transfer(&env.current_contract_address(), recipient, &leg.amount);
+        AccountingModule::add_escrowed(env, &leg.token, -leg.amount);
+    }
+
+    /// Stores a swap, keeping it alive past both funding deadlines.
+    fn save(env: &Env, swap: &Swap) {
+        let key = ("swap", &swap.id);
+        env.storage().persistent().set(&key, swap);
+        let deadline = swap.buyer_leg.funding_deadline.max(swap.seller_leg.funding_deadline);
+        Utils::extend_escrow_ttl(env, &key, deadline);
+    }
+}
@@ -0,0 +1,82 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol};
+
+use crate::types::EscrowAmount;
+use crate::errors::EscrowError;
+use crate::utils::Utils;
+
+/// Module for managing the tokens escrows may be denominated in.
+///
+/// Any SEP-41 token can be allow-listed, including the Stellar Asset Contract
+/// for native XLM, which is registered with `set_native_token`.
+pub struct TokenModule;
+
+#[contractimpl]
+impl TokenModule {
+    /// Adds a SEP-41 token to the allow-list.
+    pub fn allow_token(env: Env, token: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&("allowed_token", &token), &true);
+        Ok(())
+    }
+
+    /// Removes a token from the allow-list. Existing escrows are unaffected.
+    pub fn disallow_token(env: Env, token: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().remove(&("allowed_token", &token));
+        Ok(())
+    }
+
+    /// Checks whether a token may be used for new escrows.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        env.storage().instance().get(&("allowed_token", &token)).unwrap_or(false)
+    }
+
+    /// Records the native XLM asset contract and allow-lists it.
+    pub fn set_native_token(env: Env, token: Address) -> Result<(), EscrowError> {
+        Utils::require_admin(&env)?;
+        env.storage().instance().set(&"native_token", &token);
+        env.storage().instance().set(&("allowed_token", &token), &true);
+        Ok(())
+    }
+
+    /// Retrieves the native XLM asset contract, if registered.
+    pub fn get_native_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&"native_token")
+    }
+
+    /// Retrieves an escrow's display amount by contract-assigned escrow ID.
+    pub fn get_escrow_amount_by_id(env: Env, escrow_id: u64) -> Result<EscrowAmount, EscrowError> {
+        let transaction_id = Utils::escrow_symbol(&env, escrow_id);
+        Self::get_escrow_amount(env, transaction_id)
+    }
+
+    /// Retrieves an escrow's amount with its token's decimals and symbol for display.
+    ///
+    /// Works for both agreements and funded transactions.
+    pub fn get_escrow_amount(env: Env, transaction_id: Symbol) -> Result<EscrowAmount, EscrowError> {
+        let transaction = Utils::load_transaction(&env, &transaction_id).map_err(|_| EscrowError::AgreementNotFound)?;
+        let (token, amount) = (transaction.token, transaction.amount);
+
+        let token_client = token::Client::new(&env, &token);
+        Ok(EscrowAmount {
+            amount,
+            decimals: token_client.decimals(),
+            symbol: token_client.symbol(),
+        })
+    }
+}
+
+impl TokenModule {
+    /// Validates that a new escrow uses an allow-listed token and a positive amount.
+    pub fn validate(env: &Env, token: &Address, amount: i128) -> Result<(), EscrowError> {
+        if amount <= 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+        if !Self::is_token_allowed(env.clone(), token.clone()) {
+            return Err(EscrowError::TokenNotAllowed);
+        }
+        Ok(())
+    }
+}
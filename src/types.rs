@@ -1,6 +1,10 @@
 #![no_std]
 
-use soroban_sdk::{contracttype, Address, Symbol};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+use crate::errors::EscrowError;
+use crate::oracle::OracleModule;
+use crate::htlc::HtlcModule;
 
 /// Represents the state of a transaction in the escrow process.
 #[contracttype]
@@ -10,6 +14,8 @@ pub enum TransactionState {
     Deposit,
     Dispute,
     Complete,
+    Refunded,
+    Cancelled,
 }
 
 /// Represents an agreement between a buyer and a seller.
@@ -18,6 +24,7 @@ pub enum TransactionState {
 pub struct Agreement {
     pub buyer: Address,
     pub seller: Address,
+    pub token: Address,
     pub amount: i128,
     pub deadline: u64,
     pub state: TransactionState,
@@ -31,16 +38,33 @@ pub struct Transaction {
     pub buyer: Address,
     pub seller: Address,
     pub amount: i128,
-    pub token: Symbol,
+    pub token: Address,
+    pub deadline: u64,
     pub state: TransactionState,
 }
 
 impl Transaction {
     /// Checks if the transaction is fulfilled.
-    pub fn is_fulfilled(&self) -> bool {
-        // Logic to determine if the transaction conditions are fulfilled.
-        // This is a placeholder and should be implemented based on actual conditions.
-        true
+    ///
+    /// Hash-locked escrows are only fulfilled by revealing the preimage.
+    /// Escrows with an oracle are fulfilled once the oracle attests a
+    /// `Fulfilled` outcome; escrows without one are always fulfilled.
+    pub fn is_fulfilled(&self, env: &Env) -> bool {
+        if HtlcModule::get_hashlock(env.clone(), self.id.clone()).is_some() {
+            return false;
+        }
+        match OracleModule::get_oracle_condition(env.clone(), self.id.clone()) {
+            Some(condition) => condition.outcome == Some(AttestationOutcome::Fulfilled),
+            None => true,
+        }
+    }
+
+    /// Checks if an oracle has attested that the transaction's condition failed.
+    pub fn is_failed(&self, env: &Env) -> bool {
+        match OracleModule::get_oracle_condition(env.clone(), self.id.clone()) {
+            Some(condition) => condition.outcome == Some(AttestationOutcome::Failed),
+            None => false,
+        }
     }
 
     /// Checks if the transaction has a dispute.
@@ -55,7 +79,16 @@ impl Transaction {
 pub struct Dispute {
     pub transaction_id: Symbol,
     pub raiser: Address,
+    pub reason: DisputeReason,
+    pub remedy: ClaimedRemedy,
+    /// Arbitrators drawn to rule on the dispute; empty if none was eligible.
+    pub panel: Vec<Address>,
+    pub votes: Vec<PanelVote>,
+    pub raised_at: u64,
     pub state: DisputeState,
+    pub bond: i128,
+    /// Share of the escrow, in basis points, paid to the seller when the dispute ended.
+    pub seller_bps: Option<u32>,
 }
 
 /// Represents the state of a dispute.
@@ -64,4 +97,391 @@ pub struct Dispute {
 pub enum DisputeState {
     Open,
     Resolved,
+    Withdrawn,
+    Settled,
+    Overturned,
+    TimedOut,
+}
+
+/// An arbitrator's vote on a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PanelVote {
+    pub arbitrator: Address,
+    pub release_to_seller: bool,
+}
+
+/// Why a dispute was raised.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeReason {
+    PaymentNotReceived,
+    PaymentNotReleased,
+    WrongAmount,
+    Fraud,
+    Other,
+}
+
+/// Outcome the raiser of a dispute asks for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimedRemedy {
+    Release,
+    Refund,
+    /// Share of the escrow, in basis points, to pay the seller.
+    Split(u32),
+}
+
+/// A registered arbitrator, the dispute reasons they handle and their stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Arbitrator {
+    pub address: Address,
+    pub specialties: Vec<DisputeReason>,
+    pub stake: i128,
+    pub open_disputes: u32,
+    /// Time after which the stake can be withdrawn, once an unstake is requested.
+    pub unstake_at: Option<u64>,
+}
+
+/// Workload and performance counters for an arbitrator.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ArbitratorMetrics {
+    pub assigned: u32,
+    pub resolved: u32,
+    pub overturned: u32,
+    /// Seconds from raising to ruling, summed over resolved disputes.
+    pub total_ruling_time: u64,
+    pub average_ruling_time: u64,
+}
+
+/// Stake arbitrators must hold to be assigned disputes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorStaking {
+    pub token: Address,
+    pub min_stake: i128,
+    /// Seconds between requesting an unstake and withdrawing the stake.
+    pub cooldown: u64,
+}
+
+/// How the bond for raising a dispute is sized.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeBondAmount {
+    Flat(i128),
+    Percentage(u32),
+}
+
+/// Who receives the bond of a raiser who loses the dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeBondRecipient {
+    Counterparty,
+    Arbitrator,
+}
+
+/// Bond required to raise a dispute, paid in the escrow token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBondPolicy {
+    pub amount: DisputeBondAmount,
+    pub recipient: DisputeBondRecipient,
+}
+
+/// Minimum and maximum escrow amounts allowed for a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLimits {
+    pub min_amount: i128,
+    pub max_amount: i128,
+}
+
+/// Caps on the open escrows a single address may take part in.
+///
+/// `value_bonus_per_completed` raises `max_open_value` for every escrow the
+/// address has completed, so limits grow with the address's reputation. The
+/// total bonus is capped at `max_value_bonus`, so trading small escrows with
+/// one's own addresses cannot raise the cap without limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExposureCaps {
+    pub max_open_escrows: u32,
+    pub max_open_value: i128,
+    pub value_bonus_per_completed: i128,
+    pub max_value_bonus: i128,
+}
+
+/// Running totals of an address's escrow activity.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Exposure {
+    pub open_escrows: u32,
+    pub open_value: i128,
+    pub completed: u32,
+}
+
+/// Operations that can be paused independently.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Creation,
+    Funding,
+    Release,
+}
+
+/// Scope of an emergency pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Global,
+    Token(Address),
+    Operation(Operation),
+}
+
+/// Agreement layout stored by schema version 1, before escrows recorded a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAgreement {
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+    pub deadline: u64,
+    pub state: TransactionState,
+}
+
+/// Transaction layout stored by schema version 1, when the token was a `Symbol`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyTransaction {
+    pub id: Symbol,
+    pub buyer: Address,
+    pub seller: Address,
+    pub amount: i128,
+    pub token: Symbol,
+    pub state: TransactionState,
+}
+
+/// Roles that can be granted to addresses.
+///
+/// The admin is held by a single address and transferred with
+/// `propose_admin`/`accept_admin` rather than granted as a role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Reserved for managing protocol fees, which `TokenAccounting.fees`
+    /// tracks. No entrypoint requires it yet.
+    FeeManager,
+    ArbitratorManager,
+    Pauser,
+    Upgrader,
+}
+
+/// M-of-N approvers required before an escrow's funds can be released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproval {
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+    pub approvals: Vec<Address>,
+}
+
+/// Side an address takes in an escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartyRole {
+    Buyer,
+    Seller,
+}
+
+/// A page of escrow IDs returned by listing queries.
+///
+/// `next_cursor` is `None` once the end of the index has been reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPage {
+    pub ids: Vec<Symbol>,
+    pub next_cursor: Option<u32>,
+}
+
+/// An escrow amount together with its token's display metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowAmount {
+    pub amount: i128,
+    pub decimals: u32,
+    pub symbol: String,
+}
+
+/// Amounts of a token the contract owes, by purpose.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenAccounting {
+    pub escrowed: i128,
+    pub fees: i128,
+    pub dispute_bonds: i128,
+    pub stakes: i128,
+}
+
+impl TokenAccounting {
+    /// Total amount of the token the contract owes.
+    pub fn total(&self) -> i128 {
+        self.escrowed + self.fees + self.dispute_bonds + self.stakes
+    }
+}
+
+/// Terms for one escrow in a batch creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTerms {
+    pub buyer: Address,
+    pub seller: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub deadline: u64,
+}
+
+/// Outcome of one item in a non-atomic batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchItemResult {
+    Ok,
+    Err(EscrowError),
+}
+
+/// Represents the state of one installment of a subscription escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InstallmentState {
+    Pending,
+    Disputed,
+    Released,
+    Refunded,
+}
+
+/// An escrow funded once by the buyer and paid out to the seller in
+/// equal installments, one per period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub id: Symbol,
+    pub buyer: Address,
+    pub seller: Address,
+    pub token: Address,
+    pub installment_amount: i128,
+    pub period_ends: Vec<u64>,
+    pub installments: Vec<InstallmentState>,
+    pub cancelled: bool,
+}
+
+/// Outcome reported by an oracle for an escrow's condition.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationOutcome {
+    Fulfilled,
+    Failed,
+}
+
+/// Oracle designated to decide an escrow, with the condition it reports on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleCondition {
+    pub oracle: Address,
+    pub condition_id: Symbol,
+    pub outcome: Option<AttestationOutcome>,
+}
+
+/// SHA-256 hashlock guarding an escrow's release.
+///
+/// `preimage` is recorded once the counterparty claims.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashLock {
+    pub hashlock: BytesN<32>,
+    pub preimage: Option<Bytes>,
+}
+
+/// A payee of a multi-party escrow and its share in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payee {
+    pub address: Address,
+    pub share_bps: u32,
+}
+
+/// How a multi-party escrow's amount is split on release.
+///
+/// Each payee receives `amount * share_bps / 10000`, rounded down. The
+/// rounding remainder, `dust`, is paid to the first payee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayeeSplit {
+    pub payees: Vec<Payee>,
+    pub dust: i128,
+}
+
+/// An additional (token, amount) leg of a basket escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowLeg {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Represents the state of a two-sided swap escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SwapState {
+    Open,
+    Completed,
+    Expired,
+}
+
+/// One side's deposit in a swap escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapLeg {
+    pub party: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub funding_deadline: u64,
+    pub funded: bool,
+}
+
+/// A crypto-to-crypto escrow where both parties deposit and the contract
+/// exchanges the deposits atomically.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Swap {
+    pub id: Symbol,
+    pub buyer_leg: SwapLeg,
+    pub seller_leg: SwapLeg,
+    pub state: SwapState,
+}
+
+/// A security deposit one party posts against an escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bond {
+    pub party: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub posted: bool,
+}
+
+/// Security deposits required from both parties of an escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowBonds {
+    pub buyer_bond: Bond,
+    pub seller_bond: Bond,
+}
+
+/// Where slashed bonds are sent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlashTarget {
+    Counterparty,
+    Treasury,
 }
@@ -0,0 +1,135 @@
+#![no_std]
+
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::types::{Agreement, LegacyAgreement, LegacyTransaction, Role, Transaction, TransactionState};
+use crate::errors::EscrowError;
+use crate::access_control::AccessControlModule;
+use crate::accounting::AccountingModule;
+
+/// Storage schema version written by this build of the contract.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Maximum number of records converted by a single `migrate` call.
+pub const MAX_MIGRATION_BATCH: u32 = 25;
+
+/// Module for upgrading the contract code and migrating stored records.
+pub struct UpgradeModule;
+
+#[contractimpl]
+impl UpgradeModule {
+    /// Replaces the contract code with the uploaded WASM identified by `new_wasm_hash`.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::Upgrader)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Returns the schema version of the stored records.
+    ///
+    /// Deployments made before versioning was introduced report version 1.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&"schema_version").unwrap_or(1)
+    }
+
+    /// Converts a batch of version 1 records to the current layout.
+    ///
+    /// Version 1 records carry no token address, so they are assigned
+    /// `default_token`. Version 1 transactions carry no deadline and are
+    /// assigned `default_deadline`. Converted records that hold funds are
+    /// added to `default_token`'s escrowed total, so `rescue` cannot sweep
+    /// them. Records already in the current layout, and missing records, are
+    /// skipped.
+    /// Returns the number of records converted.
+    pub fn migrate(
+        env: Env,
+        caller: Address,
+        default_token: Address,
+        default_deadline: u64,
+        transaction_ids: Vec<Symbol>,
+    ) -> Result<u32, EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::Upgrader)?;
+
+        if transaction_ids.len() > MAX_MIGRATION_BATCH {
+            return Err(EscrowError::BatchTooLarge);
+        }
+
+        let mut migrated = 0;
+        for transaction_id in transaction_ids.iter() {
+            if Self::migrate_record(&env, &transaction_id, &default_token, default_deadline) {
+                migrated += 1;
+            }
+        }
+
+        Ok(migrated)
+    }
+
+    /// Marks the migration as complete by recording the current schema version.
+    pub fn finish_migration(env: Env, caller: Address) -> Result<(), EscrowError> {
+        AccessControlModule::require_role(&env, &caller, Role::Upgrader)?;
+        env.storage().instance().set(&"schema_version", &CURRENT_SCHEMA_VERSION);
+        Ok(())
+    }
+}
+
+impl UpgradeModule {
+    /// Converts a single legacy record, returning whether anything changed.
+    ///
+    /// The record is read as a raw field map so its layout can be told apart
+    /// before decoding: version 1 transactions have an `id` but no
+    /// `deadline`, and version 1 agreements have neither an `id` nor a `token`.
+    fn migrate_record(env: &Env, transaction_id: &Symbol, default_token: &Address, default_deadline: u64) -> bool {
+        let Some(raw) = env.storage().persistent().get::<_, Val>(transaction_id) else {
+            return false;
+        };
+        let Ok(fields) = Map::<Symbol, Val>::try_from_val(env, &raw) else {
+            return false;
+        };
+        let has_field = |name: &str| fields.contains_key(Symbol::new(env, name));
+
+        if has_field("id") && !has_field("deadline") {
+            let Ok(legacy) = LegacyTransaction::try_from_val(env, &raw) else {
+                return false;
+            };
+            let transaction = Transaction {
+                id: legacy.id,
+                buyer: legacy.buyer,
+                seller: legacy.seller,
+                amount: legacy.amount,
+                token: default_token.clone(),
+                deadline: default_deadline,
+                state: legacy.state,
+            };
+            env.storage().persistent().set(transaction_id, &transaction);
+            Self::account_for(env, default_token, &transaction.state, transaction.amount);
+            return true;
+        }
+
+        if !has_field("id") && !has_field("token") {
+            let Ok(legacy) = LegacyAgreement::try_from_val(env, &raw) else {
+                return false;
+            };
+            let agreement = Agreement {
+                buyer: legacy.buyer,
+                seller: legacy.seller,
+                token: default_token.clone(),
+                amount: legacy.amount,
+                deadline: legacy.deadline,
+                state: legacy.state,
+            };
+            env.storage().persistent().set(transaction_id, &agreement);
+            Self::account_for(env, default_token, &agreement.state, agreement.amount);
+            return true;
+        }
+
+        false
+    }
+
+    /// Adds a converted record's amount to the escrowed total if the contract
+    /// is holding it.
+    fn account_for(env: &Env, token: &Address, state: &TransactionState, amount: i128) {
+        if matches!(state, TransactionState::Deposit | TransactionState::Dispute) {
+            AccountingModule::add_escrowed(env, token, amount);
+        }
+    }
+}
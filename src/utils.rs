@@ -1,6 +1,24 @@
 #![no_std]
 
-use soroban_sdk::{Env, Timepoint};
+use soroban_sdk::{env::internal::SymbolStr, Address, Env, IntoVal, Map, Symbol, Timepoint, TryFromVal, Val};
+
+use crate::types::{Agreement, Transaction};
+use crate::errors::EscrowError;
+
+/// Approximate time between ledgers, in seconds.
+pub const LEDGER_SECONDS: u64 = 5;
+
+/// Number of ledgers closed in a day.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Lifetime kept on escrow records beyond their remaining deadline.
+pub const ESCROW_TTL_BUFFER: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Upper bound on any TTL extension.
+pub const MAX_TTL: u32 = 365 * DAY_IN_LEDGERS;
+
+/// Lifetime kept on instance storage, which holds the contract configuration.
+pub const INSTANCE_TTL: u32 = 30 * DAY_IN_LEDGERS;
 
 /// Utility functions for the escrow service.
 pub struct Utils;
@@ -17,4 +35,112 @@ impl Utils {
         let current_time = env.ledger().timestamp();
         current_time > deadline
     }
+
+    /// Loads the contract admin and requires its authorization.
+    pub fn require_admin(env: &Env) -> Result<Address, EscrowError> {
+        let admin: Address = env.storage().instance().get(&"admin").ok_or(EscrowError::NotInitialized)?;
+        admin.require_auth();
+        Self::extend_instance_ttl(env);
+        Ok(admin)
+    }
+
+    /// Calculates the TTL, in ledgers, that keeps a record alive until the
+    /// given deadline plus `ESCROW_TTL_BUFFER`.
+    pub fn ttl_until(env: &Env, deadline: Timepoint) -> u32 {
+        let remaining = deadline.saturating_sub(env.ledger().timestamp()) / LEDGER_SECONDS;
+        let ttl = remaining.saturating_add(ESCROW_TTL_BUFFER as u64);
+        ttl.min(MAX_TTL as u64) as u32
+    }
+
+    /// Extends a persistent escrow record so it outlives the given deadline.
+    ///
+    /// Records whose deadline has passed keep just `ESCROW_TTL_BUFFER`.
+    pub fn extend_escrow_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K, deadline: Timepoint) {
+        let extend_to = Self::ttl_until(env, deadline);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, extend_to.saturating_sub(DAY_IN_LEDGERS), extend_to);
+    }
+
+    /// Extends a persistent record shared by many escrows, such as an index,
+    /// an address's exposure or a token's accounting, to the maximum TTL.
+    pub fn extend_shared_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, MAX_TTL - DAY_IN_LEDGERS, MAX_TTL);
+    }
+
+    /// Converts a contract-assigned escrow ID into the `Symbol` its records
+    /// are stored under, e.g. `esc_42`.
+    pub fn escrow_symbol(env: &Env, escrow_id: u64) -> Symbol {
+        let mut digits = [0u8; 20];
+        let mut len = 0;
+        let mut remaining = escrow_id;
+        loop {
+            digits[len] = b'0' + (remaining % 10) as u8;
+            len += 1;
+            remaining /= 10;
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        let mut buf = [0u8; 24];
+        buf[..4].copy_from_slice(b"esc_");
+        for i in 0..len {
+            buf[4 + i] = digits[len - 1 - i];
+        }
+        Symbol::new(env, core::str::from_utf8(&buf[..4 + len]).unwrap())
+    }
+
+    /// Reports whether a transaction ID falls in the `esc_` range reserved for
+    /// contract-assigned escrow IDs.
+    pub fn is_reserved_id(env: &Env, transaction_id: &Symbol) -> bool {
+        match SymbolStr::try_from_val(env, transaction_id) {
+            Ok(text) => AsRef::<str>::as_ref(&text).starts_with("esc_"),
+            Err(_) => false,
+        }
+    }
+
+    /// Reports whether the record stored under an escrow ID is a `Transaction`
+    /// rather than an `Agreement`, or `None` if there is no record.
+    ///
+    /// Only transactions carry an `id` field.
+    pub fn is_transaction_record(env: &Env, transaction_id: &Symbol) -> Option<bool> {
+        let raw = env.storage().persistent().get::<_, Val>(transaction_id)?;
+        let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+        Some(fields.contains_key(Symbol::new(env, "id")))
+    }
+
+    /// Loads the escrow stored under a transaction ID as a `Transaction`,
+    /// whichever layout it is stored in.
+    ///
+    /// Agreements are stored without their ID until they are funded, so it is
+    /// taken from `transaction_id`.
+    pub fn load_transaction(env: &Env, transaction_id: &Symbol) -> Result<Transaction, EscrowError> {
+        let storage = env.storage().persistent();
+        match Self::is_transaction_record(env, transaction_id) {
+            Some(true) => storage.get(transaction_id).ok_or(EscrowError::TransactionNotFound),
+            Some(false) => {
+                let agreement: Agreement = storage.get(transaction_id).ok_or(EscrowError::TransactionNotFound)?;
+                Ok(Transaction {
+                    id: transaction_id.clone(),
+                    buyer: agreement.buyer,
+                    seller: agreement.seller,
+                    amount: agreement.amount,
+                    token: agreement.token,
+                    deadline: agreement.deadline,
+                    state: agreement.state,
+                })
+            }
+            None => Err(EscrowError::TransactionNotFound),
+        }
+    }
+
+    /// Extends the instance storage holding the contract configuration.
+    pub fn extend_instance_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL - DAY_IN_LEDGERS, INSTANCE_TTL);
+    }
 }
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use super::common::{setup};
+
+#[test]
+fn test_grant_revoke_and_renounce_role() {
+    let env = Env::default();
+    setup(&env);
+    let access_control_module = env.register_contract(None, AccessControlModule);
+
+    let pauser = Address::generate(&env);
+
+    // Grant and verify the role
+    access_control_module.grant_role(&env, &Role::Pauser, &pauser).unwrap();
+    assert!(access_control_module.has_role(&env, &Role::Pauser, &pauser));
+    assert!(!access_control_module.has_role(&env, &Role::Upgrader, &pauser));
+
+    // Revoke the role
+    access_control_module.revoke_role(&env, &Role::Pauser, &pauser).unwrap();
+    assert!(!access_control_module.has_role(&env, &Role::Pauser, &pauser));
+
+    // Grant again and renounce it
+    access_control_module.grant_role(&env, &Role::Pauser, &pauser).unwrap();
+    access_control_module.renounce_role(&env, &Role::Pauser, &pauser);
+    assert!(!access_control_module.has_role(&env, &Role::Pauser, &pauser));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_pause_without_role() {
+    let env = Env::default();
+    setup(&env);
+    let pause_module = env.register_contract(None, PauseModule);
+
+    let stranger = Address::generate(&env);
+
+    // Attempt to pause without the pauser role, which should panic
+    pause_module.pause(&env, &stranger, &PauseScope::Global).unwrap();
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let access_control_module = env.register_contract(None, AccessControlModule);
+
+    let new_admin = Address::generate(&env);
+
+    // Proposing does not transfer the admin role yet
+    access_control_module.propose_admin(&env, &new_admin).unwrap();
+    assert_eq!(access_control_module.get_admin(&env), Some(admin.clone()));
+
+    // Accepting completes the transfer, along with the roles the admin implicitly holds
+    access_control_module.accept_admin(&env).unwrap();
+    assert_eq!(access_control_module.get_admin(&env), Some(new_admin.clone()));
+    assert!(access_control_module.has_role(&env, &Role::Pauser, &new_admin));
+    assert!(!access_control_module.has_role(&env, &Role::Pauser, &admin));
+}
+
+#[test]
+#[should_panic(expected = "NoPendingAdmin")]
+fn test_accept_admin_without_proposal() {
+    let env = Env::default();
+    setup(&env);
+    let access_control_module = env.register_contract(None, AccessControlModule);
+
+    // Attempt to accept without a pending proposal, which should panic
+    access_control_module.accept_admin(&env).unwrap();
+}
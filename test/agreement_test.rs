@@ -5,6 +5,8 @@ use soroban_sdk::{
     testutils::{Address as _, MockAuth, MockAuthInvoke},
     Address, Env, IntoVal, Symbol,
 };
+use super::common::{setup, allowed_token, funded_transaction, test_token};
+use crate::utils::{DAY_IN_LEDGERS, ESCROW_TTL_BUFFER, MAX_TTL};
 
 #[test]
 fn test_create_agreement_success() {
@@ -13,17 +15,20 @@ fn test_create_agreement_success() {
 
     let buyer = Address::generate(&env);
     let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
     let transaction_id = Symbol::from_str("txn1");
     let amount = 1000i128;
     let deadline = 10000u64;
 
     // Create a new agreement
-    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &amount, &deadline).unwrap();
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &amount, &deadline).unwrap();
 
     // Retrieve the agreement and verify its details
     let agreement = agreement_module.get_agreement(&env, &transaction_id).unwrap();
     assert_eq!(agreement.buyer, buyer);
     assert_eq!(agreement.seller, seller);
+    assert_eq!(agreement.token, token);
     assert_eq!(agreement.amount, amount);
     assert_eq!(agreement.deadline, deadline);
     assert_eq!(agreement.state, TransactionState::Setup);
@@ -37,15 +42,17 @@ fn test_create_agreement_already_exists() {
 
     let buyer = Address::generate(&env);
     let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
     let transaction_id = Symbol::from_str("txn1");
     let amount = 1000i128;
     let deadline = 10000u64;
 
     // Create a new agreement
-    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &amount, &deadline).unwrap();
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &amount, &deadline).unwrap();
 
     // Attempt to create the same agreement again, which should panic
-    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &amount, &deadline).unwrap();
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &amount, &deadline).unwrap();
 }
 
 #[test]
@@ -59,3 +66,200 @@ fn test_get_agreement_not_found() {
     // Attempt to retrieve a non-existent agreement, which should panic
     agreement_module.get_agreement(&env, &transaction_id).unwrap();
 }
+
+#[test]
+fn test_create_agreement_extends_ttl_past_deadline() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let deadline = 90 * 24 * 60 * 60u64;
+
+    // Create an agreement with a 90-day deadline
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &deadline).unwrap();
+
+    // Verify the record outlives the deadline by the buffer
+    let ttl = env.storage().persistent().get_ttl(&transaction_id);
+    assert!(ttl >= Utils::ttl_until(&env, deadline) - DAY_IN_LEDGERS);
+    assert!(ttl > ESCROW_TTL_BUFFER);
+}
+
+#[test]
+fn test_create_agreement_keeps_shared_records_alive() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+
+    // Exposure and index records serve every escrow of the party, so they outlive the buffer
+    let storage = env.storage().persistent();
+    assert_eq!(storage.get_ttl(&("exposure", &buyer)), MAX_TTL);
+    assert_eq!(storage.get_ttl(&("party_escrows", &seller, &PartyRole::Seller, 0u32)), MAX_TTL);
+    assert_eq!(storage.get_ttl(&("escrow_state", &transaction_id)), MAX_TTL);
+}
+
+#[test]
+fn test_bump_extends_ttl() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+
+    // Advance the ledger so the record's TTL runs down
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += 20 * DAY_IN_LEDGERS;
+    });
+    let before = env.storage().persistent().get_ttl(&transaction_id);
+
+    // Anyone may bump the escrow
+    agreement_module.bump(&env, &transaction_id).unwrap();
+
+    let after = env.storage().persistent().get_ttl(&transaction_id);
+    assert!(after > before);
+}
+
+#[test]
+fn test_bump_transaction_and_side_records() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let payees_key = ("payees", &transaction_id);
+    env.storage().persistent().set(&payees_key, &0u32);
+    let before = env.storage().persistent().get_ttl(&payees_key);
+
+    // Bumping a transaction record also extends its per-escrow records
+    agreement_module.bump(&env, &transaction_id).unwrap();
+
+    assert!(env.storage().persistent().get_ttl(&payees_key) > before);
+    assert!(env.storage().persistent().get_ttl(&transaction_id) > before);
+}
+
+#[test]
+#[should_panic(expected = "TransactionNotFound")]
+fn test_bump_not_found() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    // Attempt to bump a non-existent escrow, which should panic
+    agreement_module.bump(&env, &Symbol::from_str("nonexistent_txn")).unwrap();
+}
+
+#[test]
+fn test_create_escrow_assigns_sequential_ids() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+
+    // Create two escrows with contract-assigned IDs
+    let first = agreement_module.create_escrow(&env, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+    let second = agreement_module.create_escrow(&env, &buyer, &seller, &token, &2000i128, &10000u64).unwrap();
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+
+    // Verify both can be looked up by ID and by key
+    assert_eq!(agreement_module.get_agreement_by_id(&env, &second).unwrap().amount, 2000);
+    let key = agreement_module.escrow_key(&env, &first);
+    assert_eq!(agreement_module.get_agreement(&env, &key).unwrap().amount, 1000);
+}
+
+#[test]
+#[should_panic(expected = "ReservedTransactionId")]
+fn test_create_agreement_rejects_reserved_id() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+
+    // Attempt to squat on the key the first generated ID would use, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("esc_1"), &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_create_agreement_requires_seller_auth() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+
+    // Only the buyer signs, so naming the seller without their consent should panic
+    env.mock_auths(&[MockAuth {
+        address: &buyer,
+        invoke: &MockAuthInvoke {
+            contract: &agreement_module,
+            fn_name: "create_agreement",
+            args: (&transaction_id, &buyer, &seller, &token, 1000i128, 10000u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    agreement_module.create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+}
+
+#[test]
+fn test_cancel_escrow_releases_exposure() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+
+    // Cancel an unfunded escrow
+    let escrow_id = agreement_module.create_escrow(&env, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+    let key = agreement_module.escrow_key(&env, &escrow_id);
+    agreement_module.cancel_escrow(&env, &key, &seller).unwrap();
+
+    // The escrow no longer counts against either party
+    assert_eq!(agreement_module.get_agreement(&env, &key).unwrap().state, TransactionState::Cancelled);
+    for party in [&buyer, &seller] {
+        let exposure = LimitsModule::get_exposure(env.clone(), party.clone());
+        assert_eq!(exposure.open_escrows, 0);
+        assert_eq!(exposure.open_value, 0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "InvalidTransactionState")]
+fn test_cancel_funded_escrow() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+
+    // Funded escrows are refunded or released instead, so this should panic
+    agreement_module.cancel_escrow(&env, &transaction.id, &transaction.buyer).unwrap();
+}
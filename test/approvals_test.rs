@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Symbol};
+use super::common::{setup, funded_transaction, test_token};
+
+#[test]
+fn test_release_after_threshold_met() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+    let auditor = Address::generate(&env);
+
+    // Require two of two extra approvers
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, compliance.clone(), auditor.clone()], &2)
+        .unwrap();
+    approval_module.approve_release(&env, &transaction_id, &compliance).unwrap();
+    approval_module.approve_release(&env, &transaction_id, &auditor).unwrap();
+
+    // Release funds now that the threshold is met
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Complete);
+}
+
+#[test]
+#[should_panic(expected = "ApprovalThresholdNotMet")]
+fn test_release_below_threshold() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+    let auditor = Address::generate(&env);
+
+    // Require two approvers but collect only one
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, compliance.clone(), auditor.clone()], &2)
+        .unwrap();
+    approval_module.approve_release(&env, &transaction_id, &compliance).unwrap();
+
+    // Attempt to release funds, which should panic
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "ApprovalThresholdNotMet")]
+fn test_high_value_release_requires_approvers() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 50_000, TransactionState::Deposit);
+
+    // Escrows of 10,000 or more need extra approvers
+    approval_module.set_high_value_threshold(&env, &10_000).unwrap();
+
+    // Attempt to release funds without any approvers, which should panic
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "AlreadyApproved")]
+fn test_approve_release_twice() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, compliance.clone()], &1)
+        .unwrap();
+    approval_module.approve_release(&env, &transaction_id, &compliance).unwrap();
+
+    // Approving a second time should panic
+    approval_module.approve_release(&env, &transaction_id, &compliance).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "ApprovalThresholdNotMet")]
+fn test_party_policy_does_not_replace_compliance() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 50_000, TransactionState::Deposit);
+    approval_module.set_high_value_threshold(&env, &10_000).unwrap();
+    approval_module
+        .set_compliance_approvers(&env, &vec![&env, Address::generate(&env)], &1)
+        .unwrap();
+
+    // The buyer approving their own policy does not satisfy compliance
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, transaction.buyer.clone()], &1)
+        .unwrap();
+    approval_module.approve_release(&env, &transaction_id, &transaction.buyer).unwrap();
+
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+}
+
+#[test]
+fn test_high_value_release_after_compliance_approval() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 50_000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+    approval_module.set_high_value_threshold(&env, &10_000).unwrap();
+    approval_module
+        .set_compliance_approvers(&env, &vec![&env, compliance.clone()], &1)
+        .unwrap();
+    approval_module.approve_compliance(&env, &transaction_id, &compliance).unwrap();
+
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Complete);
+}
+
+#[test]
+#[should_panic(expected = "ApprovalsStarted")]
+fn test_replace_approvers_after_approval() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, compliance.clone()], &1)
+        .unwrap();
+    approval_module.approve_release(&env, &transaction_id, &compliance).unwrap();
+
+    // Swapping the approvers once approvals have started should panic
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, Address::generate(&env)], &1)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "DuplicateApprover")]
+fn test_set_release_approvers_rejects_duplicates() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let compliance = Address::generate(&env);
+
+    // Listing one approver twice would make the threshold unreachable, so this should panic
+    approval_module
+        .set_release_approvers(&env, &transaction_id, &vec![&env, compliance.clone(), compliance], &2)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "DuplicateApprover")]
+fn test_set_compliance_approvers_rejects_duplicates() {
+    let env = Env::default();
+    setup(&env);
+    let approval_module = env.register_contract(None, ApprovalModule);
+
+    let compliance = Address::generate(&env);
+
+    // The same approver twice should panic
+    approval_module
+        .set_compliance_approvers(&env, &vec![&env, compliance.clone(), compliance], &2)
+        .unwrap();
+}
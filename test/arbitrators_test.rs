@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env};
+use super::common::{setup};
+
+#[test]
+fn test_register_and_remove_arbitrator() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let arbitrator = Address::generate(&env);
+
+    let specialties = vec![&env, DisputeReason::Fraud];
+    arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &specialties).unwrap();
+    assert_eq!(arbitrator_module.list_arbitrators(&env), vec![&env, arbitrator.clone()]);
+    assert_eq!(arbitrator_module.get_arbitrator(&env, &arbitrator).unwrap().specialties, specialties);
+
+    arbitrator_module.remove_arbitrator(&env, &admin, &arbitrator).unwrap();
+    assert!(arbitrator_module.list_arbitrators(&env).is_empty());
+    assert_eq!(arbitrator_module.get_arbitrator(&env, &arbitrator), None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_register_arbitrator_requires_role() {
+    let env = Env::default();
+    setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    let outsider = Address::generate(&env);
+    arbitrator_module
+        .register_arbitrator(&env, &outsider, &outsider, &vec![&env, DisputeReason::Other])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "ArbitratorNotFound")]
+fn test_remove_unknown_arbitrator() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    arbitrator_module.remove_arbitrator(&env, &admin, &Address::generate(&env)).unwrap();
+}
+
+struct Staked {
+    arbitrator: Address,
+    token: TokenClient<'static>,
+}
+
+fn staked_arbitrator(env: &Env, admin: &Address, arbitrator_module: &Address) -> Staked {
+    let token = TokenClient::new(env, &env.register_stellar_asset_contract(admin.clone()));
+    let staking = ArbitratorStaking { token: token.address.clone(), min_stake: 500, cooldown: 100 };
+    arbitrator_module.set_arbitrator_staking(env, &staking).unwrap();
+
+    let arbitrator = Address::generate(env);
+    token.mint(&arbitrator, &500);
+    arbitrator_module.register_arbitrator(env, admin, &arbitrator, &vec![env, DisputeReason::Fraud]).unwrap();
+    arbitrator_module.stake(env, &arbitrator, &500).unwrap();
+
+    Staked { arbitrator, token }
+}
+
+#[test]
+fn test_only_staked_arbitrators_are_selected() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let s = staked_arbitrator(&env, &admin, &arbitrator_module);
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+
+    // A second fraud specialist without stake is skipped
+    let unstaked = Address::generate(&env);
+    arbitrator_module.register_arbitrator(&env, &admin, &unstaked, &vec![&env, DisputeReason::Fraud]).unwrap();
+    let panel = ArbitratorModule::select_panel(&env, &DisputeReason::Fraud, &buyer, &seller);
+    assert_eq!(panel, vec![&env, s.arbitrator.clone()]);
+
+    // Requesting an unstake stops new assignments
+    arbitrator_module.request_unstake(&env, &s.arbitrator).unwrap();
+    assert!(ArbitratorModule::select_panel(&env, &DisputeReason::Fraud, &buyer, &seller).is_empty());
+}
+
+#[test]
+fn test_select_panel_excludes_conflicts() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+
+    let independent = Address::generate(&env);
+    let tied = Address::generate(&env);
+    for arbitrator in [&independent, &tied, &seller] {
+        arbitrator_module.register_arbitrator(&env, &admin, arbitrator, &vec![&env, DisputeReason::Other]).unwrap();
+    }
+    arbitrator_module.declare_conflict(&env, &tied, &tied, &buyer).unwrap();
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    // The seller and the arbitrator tied to the buyer are never drawn
+    let panel = ArbitratorModule::select_panel(&env, &DisputeReason::Other, &buyer, &seller);
+    assert_eq!(panel, vec![&env, independent]);
+}
+
+#[test]
+fn test_select_panel_draws_distinct_members() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+
+    for _ in 0..5 {
+        let arbitrator = Address::generate(&env);
+        arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::Fraud]).unwrap();
+    }
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    let panel = ArbitratorModule::select_panel(&env, &DisputeReason::Fraud, &buyer, &seller);
+    assert_eq!(panel.len(), 3);
+    for member in panel.iter() {
+        assert_eq!(panel.iter().filter(|other| other == &member).count(), 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = "InvalidThreshold")]
+fn test_set_panel_size_rejects_even_size() {
+    let env = Env::default();
+    setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    // An even panel could split its votes, so this should panic
+    arbitrator_module.set_panel_size(&env, &2).unwrap();
+}
+
+#[test]
+fn test_select_panel_trims_to_odd_count() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+
+    for _ in 0..2 {
+        let arbitrator = Address::generate(&env);
+        arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::Fraud]).unwrap();
+    }
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    // Only two candidates are eligible, and a two-member panel could tie
+    let panel = ArbitratorModule::select_panel(&env, &DisputeReason::Fraud, &buyer, &seller);
+    assert_eq!(panel.len(), 1);
+}
+
+#[test]
+fn test_unstake_after_cooldown() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let s = staked_arbitrator(&env, &admin, &arbitrator_module);
+
+    arbitrator_module.request_unstake(&env, &s.arbitrator).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += 101;
+    });
+    arbitrator_module.unstake(&env, &s.arbitrator).unwrap();
+
+    assert_eq!(s.token.balance(&s.arbitrator), 500);
+    assert_eq!(arbitrator_module.get_arbitrator(&env, &s.arbitrator).unwrap().stake, 0);
+}
+
+#[test]
+#[should_panic(expected = "ArbitratorBusy")]
+fn test_unstake_with_open_disputes() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let s = staked_arbitrator(&env, &admin, &arbitrator_module);
+
+    ArbitratorModule::open_case(&env, &s.arbitrator);
+    arbitrator_module.request_unstake(&env, &s.arbitrator).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += 101;
+    });
+    arbitrator_module.unstake(&env, &s.arbitrator).unwrap();
+}
+
+#[test]
+fn test_admin_slashes_stake() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let s = staked_arbitrator(&env, &admin, &arbitrator_module);
+    let recipient = Address::generate(&env);
+
+    arbitrator_module.slash_arbitrator(&env, &s.arbitrator, &200, &recipient).unwrap();
+
+    assert_eq!(s.token.balance(&recipient), 200);
+    assert_eq!(arbitrator_module.get_arbitrator(&env, &s.arbitrator).unwrap().stake, 300);
+}
+
+#[test]
+fn test_select_panel_skips_overloaded_arbitrators() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+
+    let arbitrator = Address::generate(&env);
+    arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::Other]).unwrap();
+    arbitrator_module.set_max_open_disputes(&env, &Some(1)).unwrap();
+
+    ArbitratorModule::open_case(&env, &arbitrator);
+    assert!(ArbitratorModule::select_panel(&env, &DisputeReason::Other, &buyer, &seller).is_empty());
+
+    ArbitratorModule::close_case(&env, &arbitrator);
+    assert_eq!(ArbitratorModule::select_panel(&env, &DisputeReason::Other, &buyer, &seller), vec![&env, arbitrator]);
+}
+
+#[test]
+#[should_panic(expected = "ArbitratorBusy")]
+fn test_remove_arbitrator_with_open_disputes() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    let s = staked_arbitrator(&env, &admin, &arbitrator_module);
+
+    // Removing an arbitrator mid-dispute should panic
+    ArbitratorModule::open_case(&env, &s.arbitrator);
+    arbitrator_module.remove_arbitrator(&env, &admin, &s.arbitrator).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "StakesOutstanding")]
+fn test_change_staking_token_while_staked() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+    staked_arbitrator(&env, &admin, &arbitrator_module);
+
+    // Switching tokens while stakes are held should panic
+    let other = env.register_stellar_asset_contract(admin.clone());
+    let staking = ArbitratorStaking { token: other, min_stake: 500, cooldown: 100 };
+    arbitrator_module.set_arbitrator_staking(&env, &staking).unwrap();
+}
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, Symbol};
+
+struct Basket {
+    transaction: Transaction,
+    usdc: TokenClient<'static>,
+    project: TokenClient<'static>,
+}
+
+fn setup(env: &Env, basket_module: &Address) -> Basket {
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    DecentralizedEscrowService::initialize(env.clone(), admin.clone()).unwrap();
+
+    let buyer = Address::generate(env);
+    let usdc = TokenClient::new(env, &env.register_stellar_asset_contract(admin.clone()));
+    let project = TokenClient::new(env, &env.register_stellar_asset_contract(admin));
+    TokenModule::allow_token(env.clone(), project.address.clone()).unwrap();
+    usdc.mint(&buyer, &1000);
+    project.mint(&buyer, &500);
+
+    // 1000 USDC as the primary leg plus 500 of the project token
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer,
+        seller: Address::generate(env),
+        amount: 1000,
+        token: usdc.address.clone(),
+        deadline: 10000,
+        state: TransactionState::Setup,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+    basket_module
+        .set_legs(env, &transaction_id, &vec![env, EscrowLeg { token: project.address.clone(), amount: 500 }])
+        .unwrap();
+
+    Basket { transaction, usdc, project }
+}
+
+#[test]
+fn test_deposit_and_release_every_leg() {
+    let env = Env::default();
+    let basket_module = env.register_contract(None, BasketModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &basket_module);
+
+    // Funding moves both legs into the contract
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+    assert_eq!(b.usdc.balance(&env.current_contract_address()), 1000);
+    assert_eq!(b.project.balance(&env.current_contract_address()), 500);
+
+    // Releasing pays out both legs to the seller
+    let funded: Transaction = env.storage().persistent().get(&b.transaction.id).unwrap();
+    fund_management_module.release_funds(&env, &funded).unwrap();
+    assert_eq!(b.usdc.balance(&b.transaction.seller), 1000);
+    assert_eq!(b.project.balance(&b.transaction.seller), 500);
+}
+
+#[test]
+fn test_refund_every_leg() {
+    let env = Env::default();
+    let basket_module = env.register_contract(None, BasketModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &basket_module);
+
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+
+    // Expire the escrow and verify both legs return to the buyer
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    fund_management_module.expire(&env, &b.transaction.id).unwrap();
+    assert_eq!(b.usdc.balance(&b.transaction.buyer), 1000);
+    assert_eq!(b.project.balance(&b.transaction.buyer), 500);
+}
+
+#[test]
+#[should_panic]
+fn test_deposit_fails_without_every_leg() {
+    let env = Env::default();
+    let basket_module = env.register_contract(None, BasketModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &basket_module);
+
+    // The buyer no longer holds the project token, so funding should panic
+    b.project.burn(&b.transaction.buyer, &500);
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+}
+
+#[test]
+fn test_paused_leg_blocks_release_before_any_transfer() {
+    let env = Env::default();
+    let basket_module = env.register_contract(None, BasketModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let batch_module = env.register_contract(None, BatchModule);
+    let b = setup(&env, &basket_module);
+
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+
+    // Pause the project token only
+    let admin = AccessControlModule::get_admin(env.clone()).unwrap();
+    PauseModule::pause(env.clone(), admin, PauseScope::Token(b.project.address.clone())).unwrap();
+
+    // A non-atomic batch reports the failure without paying the primary leg
+    let results = batch_module.release_many(&env, &vec![&env, b.transaction.id.clone()], &false).unwrap();
+    assert_eq!(results, vec![&env, BatchItemResult::Err(EscrowError::ContractPaused)]);
+    assert_eq!(b.usdc.balance(&b.transaction.seller), 0);
+    assert_eq!(b.usdc.balance(&env.current_contract_address()), 1000);
+}
+
+#[test]
+fn test_settle_pays_leg_dust_to_buyer() {
+    let env = Env::default();
+    let basket_module = env.register_contract(None, BasketModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &basket_module);
+
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+    let funded: Transaction = env.storage().persistent().get(&b.transaction.id).unwrap();
+
+    // A third to the seller rounds down; the buyer takes the rest of each leg
+    FundManagementModule::settle(&env, &funded, 3333);
+    assert_eq!(b.project.balance(&b.transaction.seller), 166);
+    assert_eq!(b.project.balance(&b.transaction.buyer), 334);
+    assert_eq!(b.project.balance(&env.current_contract_address()), 0);
+}
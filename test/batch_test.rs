@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, Symbol};
+use super::common::{setup, funded_transaction, test_token};
+use crate::batch::MAX_BATCH_SIZE;
+
+#[test]
+fn test_create_escrows() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let token = Address::generate(&env);
+    token_module.allow_token(&env, &token).unwrap();
+
+    let terms = EscrowTerms {
+        buyer: Address::generate(&env),
+        seller: Address::generate(&env),
+        token: token.clone(),
+        amount: 1000,
+        deadline: 10000,
+    };
+
+    // Create two escrows in one call
+    let escrow_ids = batch_module.create_escrows(&env, &vec![&env, terms.clone(), terms]).unwrap();
+    assert_eq!(escrow_ids, vec![&env, 1, 2]);
+}
+
+#[test]
+fn test_create_fund_and_release_escrows() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let token = test_token(&env);
+    token_module.allow_token(&env, &token).unwrap();
+    let token_client = TokenClient::new(&env, &token);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    token_client.mint(&buyer, &2000);
+    let terms = EscrowTerms { buyer: buyer.clone(), seller: seller.clone(), token, amount: 1000, deadline: 10000 };
+
+    // Create two escrows, fund both by ID and release them together
+    let escrow_ids = batch_module.create_escrows(&env, &vec![&env, terms.clone(), terms]).unwrap();
+    let mut transaction_ids = vec![&env];
+    for escrow_id in escrow_ids.iter() {
+        let transaction_id = AgreementModule::escrow_key(env.clone(), escrow_id);
+        fund_management_module.fund_escrow(&env, &transaction_id).unwrap();
+        transaction_ids.push_back(transaction_id);
+    }
+    let results = batch_module.release_many(&env, &transaction_ids, &true).unwrap();
+
+    assert_eq!(results, vec![&env, BatchItemResult::Ok, BatchItemResult::Ok]);
+    assert_eq!(token_client.balance(&buyer), 0);
+    assert_eq!(token_client.balance(&seller), 2000);
+    for transaction_id in transaction_ids.iter() {
+        assert_eq!(IndexModule::get_escrow_state(env.clone(), transaction_id), Some(TransactionState::Complete));
+    }
+}
+
+#[test]
+fn test_release_many_reports_per_item_results() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+
+    let funded = Symbol::from_str("txn1");
+    funded_transaction(&env, &funded, &test_token(&env), 1000, TransactionState::Deposit);
+    let missing = Symbol::from_str("txn2");
+
+    // Release a funded and a missing transaction without atomicity
+    let results = batch_module.release_many(&env, &vec![&env, funded, missing], &false).unwrap();
+    assert_eq!(
+        results,
+        vec![&env, BatchItemResult::Ok, BatchItemResult::Err(EscrowError::TransactionNotFound)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_expire_many_atomic() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+
+    let token = test_token(&env);
+    let expired = Symbol::from_str("txn1");
+    let pending = Symbol::from_str("txn2");
+    funded_transaction(&env, &expired, &token, 1000, TransactionState::Deposit);
+    funded_transaction(&env, &pending, &token, 1000, TransactionState::Deposit);
+
+    // Only the first deadline has passed
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    let mut extended: Transaction = env.storage().persistent().get(&pending).unwrap();
+    extended.deadline = 100_000;
+    env.storage().persistent().set(&pending, &extended);
+
+    // One item is not yet expired, so the atomic batch should panic
+    batch_module.expire_many(&env, &vec![&env, expired, pending], &true).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "BatchTooLarge")]
+fn test_release_many_too_large() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+
+    let mut transaction_ids = vec![&env];
+    for _ in 0..=MAX_BATCH_SIZE {
+        transaction_ids.push_back(Symbol::from_str("txn"));
+    }
+
+    batch_module.release_many(&env, &transaction_ids, &false).unwrap();
+}
+
+#[test]
+fn test_release_many_skips_gated_escrows() {
+    let env = Env::default();
+    setup(&env);
+    let batch_module = env.register_contract(None, BatchModule);
+
+    let gated = Symbol::from_str("txn1");
+    funded_transaction(&env, &gated, &test_token(&env), 1000, TransactionState::Deposit);
+    env.storage().persistent().set(&("oracle", &gated), &OracleCondition {
+        oracle: Address::generate(&env),
+        condition_id: Symbol::from_str("delivered"),
+        outcome: None,
+    });
+
+    // The oracle has not attested, so the escrow is left in place
+    let results = batch_module.release_many(&env, &vec![&env, gated.clone()], &false).unwrap();
+    assert_eq!(results, vec![&env, BatchItemResult::Err(EscrowError::ConditionNotMet)]);
+
+    let transaction: Transaction = env.storage().persistent().get(&gated).unwrap();
+    assert_eq!(transaction.state, TransactionState::Deposit);
+}
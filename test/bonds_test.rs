@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, Symbol};
+
+struct Bonded {
+    transaction: Transaction,
+    token: TokenClient<'static>,
+}
+
+fn setup(env: &Env, bond_module: &Address) -> Bonded {
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    DecentralizedEscrowService::initialize(env.clone(), admin.clone()).unwrap();
+
+    let buyer = Address::generate(env);
+    let seller = Address::generate(env);
+    let token = TokenClient::new(env, &env.register_stellar_asset_contract(admin));
+    TokenModule::allow_token(env.clone(), token.address.clone()).unwrap();
+    token.mint(&buyer, &1100);
+    token.mint(&seller, &200);
+
+    // 1000 escrowed, with bonds of 100 from the buyer and 200 from the seller
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        token: token.address.clone(),
+        deadline: 10000,
+        state: TransactionState::Setup,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+    bond_module.require_bonds(env, &transaction_id, &token.address, &100, &200).unwrap();
+
+    Bonded { transaction, token }
+}
+
+#[test]
+fn test_bonds_returned_on_release() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &bond_module);
+
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.seller).unwrap();
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+
+    let funded: Transaction = env.storage().persistent().get(&b.transaction.id).unwrap();
+    fund_management_module.release_funds(&env, &funded).unwrap();
+
+    assert_eq!(b.token.balance(&b.transaction.buyer), 100);
+    assert_eq!(b.token.balance(&b.transaction.seller), 1200);
+}
+
+#[test]
+#[should_panic(expected = "BondsNotPosted")]
+fn test_deposit_requires_both_bonds() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &bond_module);
+
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+}
+
+#[test]
+fn test_expire_slashes_seller_bond_to_buyer() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &bond_module);
+
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.seller).unwrap();
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    fund_management_module.expire(&env, &b.transaction.id).unwrap();
+
+    // The buyer gets the escrow, its own bond and the seller's bond
+    assert_eq!(b.token.balance(&b.transaction.buyer), 1300);
+    assert_eq!(b.token.balance(&b.transaction.seller), 0);
+}
+
+#[test]
+fn test_slash_to_treasury() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &bond_module);
+    let treasury = Address::generate(&env);
+
+    bond_module.set_slash_target(&env, &SlashTarget::Treasury, &Some(treasury.clone())).unwrap();
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.seller).unwrap();
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    fund_management_module.expire(&env, &b.transaction.id).unwrap();
+
+    assert_eq!(b.token.balance(&treasury), 200);
+    assert_eq!(b.token.balance(&b.transaction.buyer), 1100);
+}
+
+#[test]
+#[should_panic(expected = "BondsAlreadyRequired")]
+fn test_require_bonds_twice() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let b = setup(&env, &bond_module);
+
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+
+    // Changing the bond terms after a party has posted should panic
+    bond_module.require_bonds(&env, &b.transaction.id, &b.token.address, &1, &200).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidTransactionState")]
+fn test_post_bond_after_release() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let b = setup(&env, &bond_module);
+
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.seller).unwrap();
+    fund_management_module.deposit_funds(&env, &b.transaction).unwrap();
+    let funded: Transaction = env.storage().persistent().get(&b.transaction.id).unwrap();
+    fund_management_module.release_funds(&env, &funded).unwrap();
+
+    // Re-posting a returned bond should panic
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+}
+
+#[test]
+fn test_bonds_returned_on_cancel() {
+    let env = Env::default();
+    let bond_module = env.register_contract(None, BondModule);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let b = setup(&env, &bond_module);
+
+    // Only the buyer posts before the seller walks away
+    bond_module.post_bond(&env, &b.transaction.id, &b.transaction.buyer).unwrap();
+    agreement_module.cancel_escrow(&env, &b.transaction.id, &b.transaction.seller).unwrap();
+
+    assert_eq!(b.token.balance(&b.transaction.buyer), 1100);
+    assert_eq!(b.token.balance(&b.transaction.seller), 200);
+    assert!(!bond_module.get_bonds(&env, &b.transaction.id).unwrap().buyer_bond.posted);
+}
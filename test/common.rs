@@ -0,0 +1,58 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, Symbol, Vec};
+
+/// Initializes the contract under a fresh admin, mocking every authorization.
+pub fn setup(env: &Env) -> Address {
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    DecentralizedEscrowService::initialize(env.clone(), admin.clone()).unwrap();
+    admin
+}
+
+/// Allow-lists a fresh token address. The contract must already be initialized.
+pub fn allowed_token(env: &Env) -> Address {
+    let token = Address::generate(env);
+    TokenModule::allow_token(env.clone(), token.clone()).unwrap();
+    token
+}
+
+/// Registers a Stellar asset contract to escrow.
+pub fn test_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract(Address::generate(env))
+}
+
+/// Stores a transaction between fresh parties, with `amount` of `token` held
+/// by the contract and a deadline of 10000.
+pub fn funded_transaction(
+    env: &Env,
+    transaction_id: &Symbol,
+    token: &Address,
+    amount: i128,
+    state: TransactionState,
+) -> Transaction {
+    env.mock_all_auths();
+    TokenClient::new(env, token).mint(&env.current_contract_address(), &amount);
+
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: Address::generate(env),
+        seller: Address::generate(env),
+        amount,
+        token: token.clone(),
+        deadline: 10000,
+        state,
+    };
+    env.storage().persistent().set(transaction_id, &transaction);
+    transaction
+}
+
+/// Registers a fresh arbitrator with the given specialties. The contract must
+/// already be initialized.
+pub fn registered_arbitrator(env: &Env, specialties: Vec<DisputeReason>) -> Address {
+    let admin = AccessControlModule::get_admin(env.clone()).unwrap();
+    let arbitrator = Address::generate(env);
+    ArbitratorModule::register_arbitrator(env.clone(), admin, arbitrator.clone(), specialties).unwrap();
+    arbitrator
+}
@@ -3,13 +3,18 @@
 use super::*;
 use soroban_sdk::{
     testutils::{Address as _, MockAuth, MockAuthInvoke},
-    Address, Env, IntoVal, Symbol,
+    token::TokenClient,
+    vec, Address, Env, IntoVal, Symbol, Vec,
 };
+use super::common::{setup, funded_transaction, registered_arbitrator, test_token};
+use crate::dispute_resolution::DEFAULT_RULING_TIMEOUT;
 
 #[test]
 fn test_raise_dispute_success() {
     let env = Env::default();
+    setup(&env);
     let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator = registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
 
     let buyer = Address::generate(&env);
     let seller = Address::generate(&env);
@@ -21,23 +26,43 @@ fn test_raise_dispute_success() {
         buyer: buyer.clone(),
         seller: seller.clone(),
         amount: 1000,
-        token: Symbol::from_str("token"),
+        token: Address::generate(&env),
+        deadline: 10000,
         state: TransactionState::Deposit,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Raise a dispute
-    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer).unwrap();
+    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
 
     // Verify the transaction state is updated to Dispute
-    let updated_transaction: Transaction = env.storage().get(&transaction_id).unwrap();
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
     assert_eq!(updated_transaction.state, TransactionState::Dispute);
 
     // Verify the dispute is stored
-    let dispute: Dispute = env.storage().get(&transaction_id).unwrap();
+    let dispute = dispute_resolution_module.get_dispute(&env, &transaction_id).unwrap();
     assert_eq!(dispute.transaction_id, transaction_id);
     assert_eq!(dispute.raiser, buyer);
     assert_eq!(dispute.state, DisputeState::Open);
+    assert_eq!(dispute.panel, vec![&env, arbitrator]);
+    assert_eq!(dispute.bond, 0);
+}
+
+#[test]
+#[should_panic(expected = "NoArbitratorAvailable")]
+fn test_raise_dispute_without_eligible_arbitrator() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+
+    // The only arbitrator handles a different kind of dispute
+    registered_arbitrator(&env, vec![&env, DisputeReason::Fraud]);
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+
+    // With nobody to rule, raising the dispute should panic
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund)
+        .unwrap();
 }
 
 #[test]
@@ -56,13 +81,14 @@ fn test_raise_dispute_invalid_state() {
         buyer: buyer.clone(),
         seller: seller.clone(),
         amount: 1000,
-        token: Symbol::from_str("token"),
+        token: Address::generate(&env),
+        deadline: 10000,
         state: TransactionState::Setup,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Attempt to raise a dispute, which should panic
-    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer).unwrap();
+    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
 }
 
 #[test]
@@ -70,29 +96,25 @@ fn test_resolve_dispute_success() {
     let env = Env::default();
     let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
 
-    let buyer = Address::generate(&env);
-    let seller = Address::generate(&env);
     let arbitrator = Address::generate(&env);
-    let transaction_id = Symbol::from_str("txn1");
-
-    // Create a transaction in the dispute state
-    let transaction = Transaction {
-        id: transaction_id.clone(),
-        buyer: buyer.clone(),
-        seller: seller.clone(),
-        amount: 1000,
-        token: Symbol::from_str("token"),
-        state: TransactionState::Dispute,
-    };
-    env.storage().set(&transaction_id, &transaction);
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Dispute);
+    let token = TokenClient::new(&env, &transaction.token);
+    let transaction_id = transaction.id.clone();
 
     // Create a dispute
     let dispute = Dispute {
         transaction_id: transaction_id.clone(),
-        raiser: buyer.clone(),
+        raiser: transaction.buyer.clone(),
+        reason: DisputeReason::PaymentNotReceived,
+        remedy: ClaimedRemedy::Refund,
+        panel: vec![&env, arbitrator.clone()],
+        votes: Vec::new(&env),
+        raised_at: 0,
         state: DisputeState::Open,
+        bond: 0,
+        seller_bps: None,
     };
-    env.storage().set(&transaction_id, &dispute);
+    env.storage().persistent().set(&("dispute", &transaction_id), &dispute);
 
     // Mock authorization for the arbitrator
     env.mock_auths(&[MockAuth {
@@ -100,20 +122,21 @@ fn test_resolve_dispute_success() {
         invoke: &MockAuthInvoke {
             contract: &dispute_resolution_module,
             fn_name: "resolve_dispute",
-            args: (&transaction_id, &arbitrator).into_val(&env),
+            args: (&transaction_id, &arbitrator, true).into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
-    // Resolve the dispute
-    dispute_resolution_module.resolve_dispute(&env, &transaction_id, &arbitrator).unwrap();
+    // Resolve the dispute in favor of the seller
+    dispute_resolution_module.resolve_dispute(&env, &transaction_id, &arbitrator, &true).unwrap();
 
-    // Verify the transaction state is updated to Complete
-    let updated_transaction: Transaction = env.storage().get(&transaction_id).unwrap();
+    // Verify the transaction state is updated to Complete and the seller is paid
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
     assert_eq!(updated_transaction.state, TransactionState::Complete);
+    assert_eq!(token.balance(&transaction.seller), 1000);
 
     // Verify the dispute state is updated to Resolved
-    let updated_dispute: Dispute = env.storage().get(&transaction_id).unwrap();
+    let updated_dispute = dispute_resolution_module.get_dispute(&env, &transaction_id).unwrap();
     assert_eq!(updated_dispute.state, DisputeState::Resolved);
 }
 
@@ -134,19 +157,358 @@ fn test_resolve_dispute_invalid_state() {
         buyer: buyer.clone(),
         seller: seller.clone(),
         amount: 1000,
-        token: Symbol::from_str("token"),
+        token: Address::generate(&env),
+        deadline: 10000,
         state: TransactionState::Dispute,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Create a resolved dispute
     let dispute = Dispute {
         transaction_id: transaction_id.clone(),
         raiser: buyer.clone(),
+        reason: DisputeReason::PaymentNotReceived,
+        remedy: ClaimedRemedy::Refund,
+        panel: Vec::new(&env),
+        votes: Vec::new(&env),
+        raised_at: 0,
         state: DisputeState::Resolved,
+        bond: 0,
+        seller_bps: None,
     };
-    env.storage().set(&transaction_id, &dispute);
+    env.storage().persistent().set(&("dispute", &transaction_id), &dispute);
 
     // Attempt to resolve the dispute, which should panic
-    dispute_resolution_module.resolve_dispute(&env, &transaction_id, &arbitrator).unwrap();
+    dispute_resolution_module.resolve_dispute(&env, &transaction_id, &arbitrator, &true).unwrap();
+}
+
+#[test]
+fn test_dispute_bond_refunded_to_winning_raiser() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    token.mint(&transaction.buyer, &50);
+    let arbitrator = registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
+
+    // Disputes cost 5% of the escrow amount
+    let policy = DisputeBondPolicy { amount: DisputeBondAmount::Percentage(500), recipient: DisputeBondRecipient::Counterparty };
+    dispute_resolution_module.set_dispute_bond(&env, &Some(policy)).unwrap();
+
+    dispute_resolution_module.raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
+    assert_eq!(token.balance(&transaction.buyer), 0);
+
+    // The buyer wins, so gets the refund and the bond back
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &arbitrator, &false).unwrap();
+    assert_eq!(token.balance(&transaction.buyer), 1050);
+}
+
+#[test]
+fn test_dispute_bond_forfeited_to_arbitrator() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    token.mint(&transaction.buyer, &25);
+    let arbitrator = registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
+
+    let policy = DisputeBondPolicy { amount: DisputeBondAmount::Flat(25), recipient: DisputeBondRecipient::Arbitrator };
+    dispute_resolution_module.set_dispute_bond(&env, &Some(policy)).unwrap();
+
+    dispute_resolution_module.raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
+
+    // The buyer loses, so the bond goes to the arbitrator
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &arbitrator, &true).unwrap();
+    assert_eq!(token.balance(&arbitrator), 25);
+    assert_eq!(token.balance(&transaction.seller), 1000);
+    assert_eq!(token.balance(&transaction.buyer), 0);
+}
+
+#[test]
+fn test_withdraw_dispute_returns_to_deposit() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    token.mint(&transaction.buyer, &25);
+    let policy = DisputeBondPolicy { amount: DisputeBondAmount::Flat(25), recipient: DisputeBondRecipient::Arbitrator };
+    dispute_resolution_module.set_dispute_bond(&env, &Some(policy)).unwrap();
+    dispute_resolution_module.raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
+
+    dispute_resolution_module.withdraw_dispute(&env, &transaction.id).unwrap();
+
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction.id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Deposit);
+    let dispute = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap();
+    assert_eq!(dispute.state, DisputeState::Withdrawn);
+    assert_eq!(dispute.seller_bps, None);
+
+    // The withdrawn bond goes to the counterparty
+    assert_eq!(token.balance(&transaction.buyer), 0);
+    assert_eq!(token.balance(&transaction.seller), 25);
+}
+
+#[test]
+#[should_panic(expected = "InvalidDisputeState")]
+fn test_withdraw_dispute_after_vote() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    for _ in 0..3 {
+        let arbitrator = Address::generate(&env);
+        arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::WrongAmount]).unwrap();
+    }
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::WrongAmount, &ClaimedRemedy::Refund)
+        .unwrap();
+    let panel = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().panel;
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &panel.get_unchecked(0), &true).unwrap();
+
+    // Withdrawing once the panel has started voting should panic
+    dispute_resolution_module.withdraw_dispute(&env, &transaction.id).unwrap();
+}
+
+#[test]
+fn test_settle_dispute_splits_escrow() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReleased]);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    dispute_resolution_module.raise_dispute(&env, &transaction.id, &transaction.seller, &DisputeReason::PaymentNotReleased, &ClaimedRemedy::Release).unwrap();
+
+    // The parties agree the seller keeps 60%
+    dispute_resolution_module.settle_dispute(&env, &transaction.id, &6000).unwrap();
+
+    assert_eq!(token.balance(&transaction.seller), 600);
+    assert_eq!(token.balance(&transaction.buyer), 400);
+    let dispute = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap();
+    assert_eq!(dispute.state, DisputeState::Settled);
+    assert_eq!(dispute.seller_bps, Some(6000));
+}
+
+#[test]
+fn test_raise_dispute_routes_by_reason() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    let generalist = Address::generate(&env);
+    let fraud_specialist = Address::generate(&env);
+    arbitrator_module.register_arbitrator(&env, &admin, &generalist, &vec![&env, DisputeReason::Other]).unwrap();
+    arbitrator_module.register_arbitrator(&env, &admin, &fraud_specialist, &vec![&env, DisputeReason::Fraud]).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::Fraud, &ClaimedRemedy::Split(2000))
+        .unwrap();
+
+    let dispute = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap();
+    assert_eq!(dispute.reason, DisputeReason::Fraud);
+    assert_eq!(dispute.remedy, ClaimedRemedy::Split(2000));
+    assert_eq!(dispute.panel, vec![&env, fraud_specialist]);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_resolve_dispute_by_unassigned_arbitrator() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    let assigned = Address::generate(&env);
+    arbitrator_module.register_arbitrator(&env, &admin, &assigned, &vec![&env, DisputeReason::WrongAmount]).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::WrongAmount, &ClaimedRemedy::Refund)
+        .unwrap();
+
+    // Only panel members may rule
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &Address::generate(&env), &false).unwrap();
+}
+
+#[test]
+fn test_overturn_ruling_slashes_arbitrator() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    let staking = ArbitratorStaking { token: token.address.clone(), min_stake: 300, cooldown: 100 };
+    arbitrator_module.set_arbitrator_staking(&env, &staking).unwrap();
+    let arbitrator = Address::generate(&env);
+    token.mint(&arbitrator, &300);
+    arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::Fraud]).unwrap();
+    arbitrator_module.stake(&env, &arbitrator, &300).unwrap();
+
+    let panel_member = Address::generate(&env);
+    dispute_resolution_module.set_appeal_panel(&env, &vec![&env, panel_member.clone()]).unwrap();
+
+    // The arbitrator wrongly rules for the seller
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::Fraud, &ClaimedRemedy::Refund)
+        .unwrap();
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &arbitrator, &true).unwrap();
+
+    dispute_resolution_module.overturn_ruling(&env, &panel_member, &transaction.id, &300).unwrap();
+
+    assert_eq!(token.balance(&transaction.buyer), 300);
+    assert_eq!(dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().state, DisputeState::Overturned);
+    assert_eq!(arbitrator_module.get_arbitrator(&env, &arbitrator).unwrap().stake, 0);
+}
+
+#[test]
+fn test_panel_rules_by_majority() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    for _ in 0..3 {
+        let arbitrator = Address::generate(&env);
+        arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::WrongAmount]).unwrap();
+    }
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::WrongAmount, &ClaimedRemedy::Refund)
+        .unwrap();
+    let panel = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().panel;
+    assert_eq!(panel.len(), 3);
+
+    // One vote is not a majority of three
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &panel.get_unchecked(0), &false).unwrap();
+    assert_eq!(dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().state, DisputeState::Open);
+
+    // The second agreeing vote decides the dispute
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &panel.get_unchecked(1), &false).unwrap();
+    assert_eq!(dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().state, DisputeState::Resolved);
+    assert_eq!(token.balance(&transaction.buyer), 1000);
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_rule_on_timeout_before_timeout() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+
+    // The panel still has time to rule, so this should panic
+    dispute_resolution_module.rule_on_timeout(&env, &transaction.id).unwrap();
+}
+
+#[test]
+fn test_rule_on_timeout_splits_without_votes() {
+    let env = Env::default();
+    setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+    dispute_resolution_module.set_ruling_timeout(&env, &600).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+
+    // Nobody votes before the timeout, so the escrow is split evenly
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += 601;
+    });
+    dispute_resolution_module.rule_on_timeout(&env, &transaction.id).unwrap();
+
+    let dispute = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap();
+    assert_eq!(dispute.state, DisputeState::TimedOut);
+    assert_eq!(dispute.seller_bps, Some(5000));
+    assert_eq!(token.balance(&transaction.buyer), 500);
+    assert_eq!(token.balance(&transaction.seller), 500);
+}
+
+#[test]
+fn test_rule_on_timeout_follows_plurality() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    for _ in 0..3 {
+        let arbitrator = Address::generate(&env);
+        arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::WrongAmount]).unwrap();
+    }
+    arbitrator_module.set_panel_size(&env, &3).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    let token = TokenClient::new(&env, &transaction.token);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.buyer, &DisputeReason::WrongAmount, &ClaimedRemedy::Refund)
+        .unwrap();
+    let panel = dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().panel;
+
+    // A single vote is not a majority, but it carries the dispute once the panel times out
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &panel.get_unchecked(0), &false).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += DEFAULT_RULING_TIMEOUT + 1;
+    });
+    dispute_resolution_module.rule_on_timeout(&env, &transaction.id).unwrap();
+
+    assert_eq!(dispute_resolution_module.get_dispute(&env, &transaction.id).unwrap().state, DisputeState::Resolved);
+    assert_eq!(token.balance(&transaction.buyer), 1000);
+}
+
+#[test]
+fn test_arbitrator_metrics() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let arbitrator_module = env.register_contract(None, ArbitratorModule);
+
+    let arbitrator = Address::generate(&env);
+    arbitrator_module.register_arbitrator(&env, &admin, &arbitrator, &vec![&env, DisputeReason::Other]).unwrap();
+    let panel_member = Address::generate(&env);
+    dispute_resolution_module.set_appeal_panel(&env, &vec![&env, panel_member.clone()]).unwrap();
+
+    let transaction = funded_transaction(&env, &Symbol::from_str("txn1"), &test_token(&env), 1000, TransactionState::Deposit);
+    dispute_resolution_module
+        .raise_dispute(&env, &transaction.id, &transaction.seller, &DisputeReason::Other, &ClaimedRemedy::Release)
+        .unwrap();
+    assert_eq!(dispute_resolution_module.get_arbitrator_metrics(&env, &arbitrator).assigned, 1);
+
+    // The ruling comes 600 seconds after the dispute was raised
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += 600;
+    });
+    dispute_resolution_module.resolve_dispute(&env, &transaction.id, &arbitrator, &true).unwrap();
+    dispute_resolution_module.overturn_ruling(&env, &panel_member, &transaction.id, &0).unwrap();
+
+    let metrics = dispute_resolution_module.get_arbitrator_metrics(&env, &arbitrator);
+    assert_eq!(metrics.resolved, 1);
+    assert_eq!(metrics.overturned, 1);
+    assert_eq!(metrics.average_ruling_time, 600);
 }
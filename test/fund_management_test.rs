@@ -4,8 +4,9 @@ use super::*;
 use soroban_sdk::{
     testutils::{Address as _, MockAuth, MockAuthInvoke},
     token::TokenClient,
-    Address, Env, IntoVal, Symbol,
+    vec, Address, Env, IntoVal, Symbol,
 };
+use super::common::{setup, funded_transaction, registered_arbitrator, test_token};
 
 #[test]
 fn test_deposit_funds_success() {
@@ -29,9 +30,10 @@ fn test_deposit_funds_success() {
         seller: seller.clone(),
         amount,
         token: token.clone(),
+        deadline: 10000,
         state: TransactionState::Setup,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Mock authorization for the buyer
     env.mock_auths(&[MockAuth {
@@ -48,7 +50,7 @@ fn test_deposit_funds_success() {
     fund_management_module.deposit_funds(&env, &transaction).unwrap();
 
     // Verify the transaction state is updated to Deposit
-    let updated_transaction: Transaction = env.storage().get(&transaction_id).unwrap();
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
     assert_eq!(updated_transaction.state, TransactionState::Deposit);
 
     // Verify the funds are transferred to the contract
@@ -74,9 +76,10 @@ fn test_deposit_funds_invalid_state() {
         seller: seller.clone(),
         amount,
         token: token.clone(),
+        deadline: 10000,
         state: TransactionState::Deposit,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Attempt to deposit funds into escrow, which should panic
     fund_management_module.deposit_funds(&env, &transaction).unwrap();
@@ -104,15 +107,16 @@ fn test_release_funds_success() {
         seller: seller.clone(),
         amount,
         token: token.clone(),
+        deadline: 10000,
         state: TransactionState::Deposit,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Release funds from escrow
     fund_management_module.release_funds(&env, &transaction).unwrap();
 
     // Verify the transaction state is updated to Complete
-    let updated_transaction: Transaction = env.storage().get(&transaction_id).unwrap();
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
     assert_eq!(updated_transaction.state, TransactionState::Complete);
 
     // Verify the funds are transferred to the seller
@@ -138,10 +142,201 @@ fn test_release_funds_invalid_state() {
         seller: seller.clone(),
         amount,
         token: token.clone(),
+        deadline: 10000,
         state: TransactionState::Setup,
     };
-    env.storage().set(&transaction_id, &transaction);
+    env.storage().persistent().set(&transaction_id, &transaction);
 
     // Attempt to release funds from escrow, which should panic
     fund_management_module.release_funds(&env, &transaction).unwrap();
 }
+
+#[test]
+fn test_expire_refunds_buyer() {
+    let env = Env::default();
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let amount = 1000i128;
+    let token = env.register_stellar_asset_contract(buyer.clone());
+
+    let token_client = TokenClient::new(&env, &token);
+    token_client.mint(&env.current_contract_address(), &amount);
+
+    // Create a funded transaction with a deadline of 10000
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount,
+        token: token.clone(),
+        deadline: 10000,
+        state: TransactionState::Deposit,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+
+    // Move past the deadline and expire the escrow
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    fund_management_module.expire(&env, &transaction_id).unwrap();
+
+    // Verify the buyer is refunded
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Refunded);
+    assert_eq!(token_client.balance(&buyer), amount);
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_expire_before_deadline() {
+    let env = Env::default();
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let token = env.register_stellar_asset_contract(buyer.clone());
+
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        token: token.clone(),
+        deadline: 10000,
+        state: TransactionState::Deposit,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+
+    // Attempt to expire before the deadline, which should panic
+    fund_management_module.expire(&env, &transaction_id).unwrap();
+}
+
+#[test]
+fn test_claim_after_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let amount = 1000i128;
+    let token = env.register_stellar_asset_contract(buyer.clone());
+
+    let token_client = TokenClient::new(&env, &token);
+    token_client.mint(&env.current_contract_address(), &amount);
+
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount,
+        token: token.clone(),
+        deadline: 10000,
+        state: TransactionState::Deposit,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+
+    // Agree to auto-release at 5000 and let the buyer stay silent
+    fund_management_module.set_auto_release(&env, &transaction_id, &5000).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 5001;
+    });
+
+    // Anyone may claim on the seller's behalf
+    fund_management_module.claim_after_timeout(&env, &transaction_id).unwrap();
+
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Complete);
+    assert_eq!(token_client.balance(&seller), amount);
+}
+
+#[test]
+#[should_panic(expected = "ConditionNotMet")]
+fn test_claim_after_timeout_waits_for_oracle() {
+    let env = Env::default();
+    setup(&env);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let oracle_module = env.register_contract(None, OracleModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    oracle_module
+        .set_oracle(&env, &transaction_id, &Address::generate(&env), &Symbol::from_str("delivered"))
+        .unwrap();
+    fund_management_module.set_auto_release(&env, &transaction_id, &5000).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 5001;
+    });
+
+    // The oracle has not attested, so the timeout claim should panic
+    fund_management_module.claim_after_timeout(&env, &transaction_id).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidTransactionState")]
+fn test_claim_after_timeout_disputed() {
+    let env = Env::default();
+    setup(&env);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let token = env.register_stellar_asset_contract(buyer.clone());
+
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        token: token.clone(),
+        deadline: 10000,
+        state: TransactionState::Deposit,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+
+    // The buyer disputes before the timeout
+    fund_management_module.set_auto_release(&env, &transaction_id, &5000).unwrap();
+    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 5001;
+    });
+
+    // Attempt to claim a disputed escrow, which should panic
+    fund_management_module.claim_after_timeout(&env, &transaction_id).unwrap();
+}
+
+#[test]
+fn test_fund_and_release_by_id() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+    let payee_module = env.register_contract(None, PayeeModule);
+
+    let token = test_token(&env);
+    TokenModule::allow_token(env.clone(), token.clone()).unwrap();
+    let token_client = TokenClient::new(&env, &token);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    token_client.mint(&buyer, &1000);
+
+    // Create an escrow and drive it through its lifecycle using only its numeric ID
+    let escrow_id = agreement_module.create_escrow(&env, &buyer, &seller, &token, &1000i128, &10000u64).unwrap();
+    payee_module
+        .set_payees_by_id(&env, &escrow_id, &vec![&env, Payee { address: seller.clone(), share_bps: 10000 }])
+        .unwrap();
+    assert_eq!(payee_module.get_payees_by_id(&env, &escrow_id).unwrap().payees.len(), 1);
+    fund_management_module.fund_escrow_by_id(&env, &escrow_id).unwrap();
+    fund_management_module.release_escrow_by_id(&env, &escrow_id).unwrap();
+
+    assert_eq!(token_client.balance(&seller), 1000);
+    assert_eq!(IndexModule::get_escrow_state_by_id(env.clone(), escrow_id), Some(TransactionState::Complete));
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token::TokenClient,
+    Address, Bytes, Env, IntoVal, Symbol,
+};
+use super::common::{setup, funded_transaction, test_token};
+
+/// Stores a transaction locked behind the hash of `preimage`, then funds it.
+fn hashlocked_transaction(env: &Env, htlc_module: &Address, transaction_id: &Symbol, preimage: &Bytes) -> Transaction {
+    let mut transaction = funded_transaction(env, transaction_id, &test_token(env), 1000, TransactionState::Setup);
+    htlc_module.set_hashlock(env, transaction_id, &env.crypto().sha256(preimage)).unwrap();
+
+    transaction.state = TransactionState::Deposit;
+    env.storage().persistent().set(transaction_id, &transaction);
+    transaction
+}
+
+#[test]
+fn test_claim_with_preimage() {
+    let env = Env::default();
+    let htlc_module = env.register_contract(None, HtlcModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+
+    // Lock the escrow behind the secret's hash
+    let transaction = hashlocked_transaction(&env, &htlc_module, &transaction_id, &preimage);
+    assert!(!transaction.is_fulfilled(&env));
+
+    // The seller claims by revealing the secret
+    htlc_module.claim_with_preimage(&env, &transaction_id, &preimage).unwrap();
+
+    assert_eq!(TokenClient::new(&env, &transaction.token).balance(&transaction.seller), 1000);
+    assert_eq!(htlc_module.get_hashlock(&env, &transaction_id).unwrap().preimage, Some(preimage.clone()));
+
+    // Verify the preimage was published
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "htlc_claimed"), transaction_id).into_val(&env));
+    assert_eq!(data, preimage.into_val(&env));
+}
+
+#[test]
+#[should_panic(expected = "InvalidPreimage")]
+fn test_claim_with_wrong_preimage() {
+    let env = Env::default();
+    let htlc_module = env.register_contract(None, HtlcModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    hashlocked_transaction(&env, &htlc_module, &transaction_id, &Bytes::from_slice(&env, b"swap secret"));
+
+    // Attempt to claim with the wrong secret, which should panic
+    htlc_module
+        .claim_with_preimage(&env, &transaction_id, &Bytes::from_slice(&env, b"guess"))
+        .unwrap();
+}
+
+#[test]
+fn test_refund_after_timelock() {
+    let env = Env::default();
+    let htlc_module = env.register_contract(None, HtlcModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let transaction = hashlocked_transaction(&env, &htlc_module, &transaction_id, &preimage);
+
+    // Nobody claims before the deadline, so the buyer is refunded
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 10001;
+    });
+    assert!(htlc_module.try_claim_with_preimage(&env, &transaction_id, &preimage).is_err());
+    fund_management_module.expire(&env, &transaction_id).unwrap();
+
+    assert_eq!(TokenClient::new(&env, &transaction.token).balance(&transaction.buyer), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HashlockAlreadySet")]
+fn test_set_hashlock_twice() {
+    let env = Env::default();
+    let htlc_module = env.register_contract(None, HtlcModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Setup);
+    htlc_module
+        .set_hashlock(&env, &transaction_id, &env.crypto().sha256(&Bytes::from_slice(&env, b"swap secret")))
+        .unwrap();
+
+    // Replacing the hashlock should panic
+    htlc_module
+        .set_hashlock(&env, &transaction_id, &env.crypto().sha256(&Bytes::from_slice(&env, b"other secret")))
+        .unwrap();
+}
+
+#[test]
+fn test_claim_while_release_paused() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let htlc_module = env.register_contract(None, HtlcModule);
+    let pause_module = env.register_contract(None, PauseModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let transaction = hashlocked_transaction(&env, &htlc_module, &transaction_id, &preimage);
+
+    // Pausing releases does not freeze the seller's claim window
+    pause_module
+        .pause(&env, &admin, &PauseScope::Operation(Operation::Release))
+        .unwrap();
+    htlc_module.claim_with_preimage(&env, &transaction_id, &preimage).unwrap();
+
+    assert_eq!(TokenClient::new(&env, &transaction.token).balance(&transaction.seller), 1000);
+}
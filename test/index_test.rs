@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Symbol};
+use super::common::{setup, allowed_token, registered_arbitrator};
+use crate::index::BUCKET_SIZE;
+
+#[test]
+fn test_list_escrows_by_party() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let index_module = env.register_contract(None, IndexModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+
+    // Create three agreements between the same parties
+    for id in ["txn1", "txn2", "txn3"] {
+        agreement_module
+            .create_agreement(&env, &Symbol::from_str(id), &buyer, &seller, &token, &1000i128, &10000u64)
+            .unwrap();
+    }
+
+    // Page through the buyer's escrows two at a time
+    let page = index_module.list_escrows_by_party(&env, &buyer, &PartyRole::Buyer, &None, &0, &2);
+    assert_eq!(page.ids, vec![&env, Symbol::from_str("txn1"), Symbol::from_str("txn2")]);
+    assert_eq!(page.next_cursor, Some(2));
+
+    let page = index_module.list_escrows_by_party(&env, &buyer, &PartyRole::Buyer, &None, &2, &2);
+    assert_eq!(page.ids, vec![&env, Symbol::from_str("txn3")]);
+    assert_eq!(page.next_cursor, None);
+
+    // The buyer has no escrows as a seller
+    let page = index_module.list_escrows_by_party(&env, &buyer, &PartyRole::Seller, &None, &0, &10);
+    assert!(page.ids.is_empty());
+}
+
+#[test]
+fn test_list_escrows_by_state_follows_transitions() {
+    let env = Env::default();
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let index_module = env.register_contract(None, IndexModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    setup(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    registered_arbitrator(&env, vec![&env, DisputeReason::PaymentNotReceived]);
+
+    agreement_module
+        .create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+    let page = index_module.list_escrows_by_state(&env, &TransactionState::Setup, &0, &10);
+    assert_eq!(page.ids, vec![&env, transaction_id.clone()]);
+
+    // Move the escrow to Deposit and raise a dispute
+    IndexModule::set_state(&env, &transaction_id, TransactionState::Deposit);
+    env.storage().persistent().set(&transaction_id, &Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        token: token.clone(),
+        deadline: 10000,
+        state: TransactionState::Deposit,
+    });
+    dispute_resolution_module.raise_dispute(&env, &transaction_id, &buyer, &DisputeReason::PaymentNotReceived, &ClaimedRemedy::Refund).unwrap();
+
+    // Verify the escrow only appears under its current state
+    assert!(index_module.list_escrows_by_state(&env, &TransactionState::Setup, &0, &10).ids.is_empty());
+    assert!(index_module.list_escrows_by_state(&env, &TransactionState::Deposit, &0, &10).ids.is_empty());
+    let page = index_module.list_escrows_by_state(&env, &TransactionState::Dispute, &0, &10);
+    assert_eq!(page.ids, vec![&env, transaction_id.clone()]);
+
+    // The party listing honours the state filter
+    let page = index_module.list_escrows_by_party(&env, &seller, &PartyRole::Seller, &Some(TransactionState::Dispute), &0, &10);
+    assert_eq!(page.ids, vec![&env, transaction_id]);
+}
+
+#[test]
+fn test_list_escrows_by_state_spans_buckets() {
+    let env = Env::default();
+    let index_module = env.register_contract(None, IndexModule);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+
+    // Fill one bucket and spill into a second
+    for id in 0..=BUCKET_SIZE as u64 {
+        IndexModule::add_escrow(&env, &Utils::escrow_symbol(&env, id), &buyer, &seller, TransactionState::Setup);
+    }
+    let page = index_module.list_escrows_by_state(&env, &TransactionState::Setup, &(BUCKET_SIZE - 1), &5);
+    assert_eq!(
+        page.ids,
+        vec![&env, Utils::escrow_symbol(&env, BUCKET_SIZE as u64 - 1), Utils::escrow_symbol(&env, BUCKET_SIZE as u64)]
+    );
+    assert_eq!(page.next_cursor, None);
+
+    // Moving an escrow out fills its slot with the last entry, emptying the second bucket
+    IndexModule::set_state(&env, &Utils::escrow_symbol(&env, 0), TransactionState::Deposit);
+    let page = index_module.list_escrows_by_state(&env, &TransactionState::Setup, &0, &1);
+    assert_eq!(page.ids, vec![&env, Utils::escrow_symbol(&env, BUCKET_SIZE as u64)]);
+    let page = index_module.list_escrows_by_state(&env, &TransactionState::Setup, &(BUCKET_SIZE - 1), &5);
+    assert_eq!(page.ids, vec![&env, Utils::escrow_symbol(&env, BUCKET_SIZE as u64 - 1)]);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_list_escrows_by_party_spans_buckets() {
+    let env = Env::default();
+    let index_module = env.register_contract(None, IndexModule);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+
+    for id in 0..=BUCKET_SIZE as u64 {
+        IndexModule::add_escrow(&env, &Utils::escrow_symbol(&env, id), &buyer, &seller, TransactionState::Setup);
+    }
+
+    // The seller's index continues into a second bucket
+    let page = index_module.list_escrows_by_party(&env, &seller, &PartyRole::Seller, &None, &(BUCKET_SIZE - 1), &5);
+    assert_eq!(
+        page.ids,
+        vec![&env, Utils::escrow_symbol(&env, BUCKET_SIZE as u64 - 1), Utils::escrow_symbol(&env, BUCKET_SIZE as u64)]
+    );
+    assert_eq!(page.next_cursor, None);
+
+    // A filtered page stops after one bucket's worth of entries
+    let page = index_module.list_escrows_by_party(&env, &seller, &PartyRole::Seller, &Some(TransactionState::Deposit), &0, &5);
+    assert!(page.ids.is_empty());
+    assert_eq!(page.next_cursor, Some(BUCKET_SIZE));
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+use super::common::{setup, allowed_token};
+
+#[test]
+#[should_panic(expected = "AmountAboveMaximum")]
+fn test_create_agreement_above_token_maximum() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let limits_module = env.register_contract(None, LimitsModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    // Configure a 10..=500 range for the token
+    limits_module
+        .set_token_limits(&env, &token, &TokenLimits { min_amount: 10, max_amount: 500 })
+        .unwrap();
+
+    // Attempt to create an agreement above the maximum, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "OpenEscrowLimitExceeded")]
+fn test_create_agreement_open_escrow_limit() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let limits_module = env.register_contract(None, LimitsModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    // Allow a single open escrow per address
+    limits_module
+        .set_exposure_caps(&env, &ExposureCaps { max_open_escrows: 1, max_open_value: 10_000, value_bonus_per_completed: 0, max_value_bonus: 0 })
+        .unwrap();
+
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &buyer, &seller, &token, &100i128, &10000u64)
+        .unwrap();
+
+    // The second open escrow exceeds the cap, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn2"), &buyer, &seller, &token, &100i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+fn test_completed_escrows_raise_value_cap() {
+    let env = Env::default();
+    setup(&env);
+    let limits_module = env.register_contract(None, LimitsModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    limits_module
+        .set_exposure_caps(&env, &ExposureCaps {
+            max_open_escrows: 5,
+            max_open_value: 1000,
+            value_bonus_per_completed: 500,
+            max_value_bonus: 1000,
+        })
+        .unwrap();
+
+    // Open and complete an escrow to build reputation
+    LimitsModule::open_escrow(&env, &buyer, &seller, &token, 1000).unwrap();
+    LimitsModule::close_escrow(&env, &buyer, &seller, 1000, true);
+
+    // The completed trade raises the value cap to 1500
+    LimitsModule::open_escrow(&env, &buyer, &seller, &token, 1500).unwrap();
+
+    let exposure = limits_module.get_exposure(&env, &buyer);
+    assert_eq!(exposure.open_escrows, 1);
+    assert_eq!(exposure.open_value, 1500);
+    assert_eq!(exposure.completed, 1);
+}
+
+#[test]
+#[should_panic(expected = "OpenValueLimitExceeded")]
+fn test_value_bonus_is_capped() {
+    let env = Env::default();
+    setup(&env);
+    let limits_module = env.register_contract(None, LimitsModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    limits_module
+        .set_exposure_caps(&env, &ExposureCaps {
+            max_open_escrows: 5,
+            max_open_value: 1000,
+            value_bonus_per_completed: 500,
+            max_value_bonus: 1000,
+        })
+        .unwrap();
+
+    // Complete many small trades between the same two addresses
+    for _ in 0..10 {
+        LimitsModule::open_escrow(&env, &buyer, &seller, &token, 1).unwrap();
+        LimitsModule::close_escrow(&env, &buyer, &seller, 1, true);
+    }
+
+    // The bonus stops at 1000, so the cap stays at 2000 and this should panic
+    LimitsModule::open_escrow(&env, &buyer, &seller, &token, 2001).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "SelfEscrow")]
+fn test_create_agreement_with_self() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let party = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    // Attempt to trade with oneself, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &party, &party, &token, &100i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidAmount")]
+fn test_set_token_limits_inverted_range() {
+    let env = Env::default();
+    setup(&env);
+    let limits_module = env.register_contract(None, LimitsModule);
+
+    limits_module
+        .set_token_limits(&env, &Address::generate(&env), &TokenLimits { min_amount: 500, max_amount: 10 })
+        .unwrap();
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, Symbol};
+use super::common::{funded_transaction, test_token};
+
+#[test]
+fn test_attest_fulfilled_releases_funds() {
+    let env = Env::default();
+    let oracle_module = env.register_contract(None, OracleModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let oracle = Address::generate(&env);
+
+    // Designate a shipping oracle; the escrow is not fulfilled until it attests
+    oracle_module.set_oracle(&env, &transaction_id, &oracle, &Symbol::from_str("delivered")).unwrap();
+    assert!(!transaction.is_fulfilled(&env));
+
+    oracle_module.attest(&env, &transaction_id, &oracle, &AttestationOutcome::Fulfilled).unwrap();
+
+    // Verify the funds went to the seller
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Complete);
+    assert_eq!(TokenClient::new(&env, &transaction.token).balance(&transaction.seller), 1000);
+}
+
+#[test]
+fn test_attest_failed_refunds_buyer() {
+    let env = Env::default();
+    let oracle_module = env.register_contract(None, OracleModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let oracle = Address::generate(&env);
+
+    oracle_module.set_oracle(&env, &transaction_id, &oracle, &Symbol::from_str("delivered")).unwrap();
+    oracle_module.attest(&env, &transaction_id, &oracle, &AttestationOutcome::Failed).unwrap();
+
+    // Verify the buyer was refunded
+    let updated_transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(updated_transaction.state, TransactionState::Refunded);
+    assert_eq!(TokenClient::new(&env, &transaction.token).balance(&transaction.buyer), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_attest_wrong_oracle() {
+    let env = Env::default();
+    let oracle_module = env.register_contract(None, OracleModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let oracle = Address::generate(&env);
+    let impostor = Address::generate(&env);
+
+    oracle_module.set_oracle(&env, &transaction_id, &oracle, &Symbol::from_str("delivered")).unwrap();
+
+    // Attempt to attest from another address, which should panic
+    oracle_module.attest(&env, &transaction_id, &impostor, &AttestationOutcome::Fulfilled).unwrap();
+}
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, Symbol, Vec};
+use super::common::{setup, allowed_token};
+
+#[test]
+fn test_pause_and_unpause() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let pause_module = env.register_contract(None, PauseModule);
+
+    let scope = PauseScope::Operation(Operation::Funding);
+
+    // Pause funding and verify the scope reports as paused
+    pause_module.pause(&env, &admin, &scope).unwrap();
+    assert!(pause_module.is_paused(&env, &scope));
+    assert!(!pause_module.is_paused(&env, &PauseScope::Global));
+
+    // Unpause and verify the scope is cleared
+    pause_module.unpause(&env, &admin, &scope).unwrap();
+    assert!(!pause_module.is_paused(&env, &scope));
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn test_create_agreement_token_paused() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let pause_module = env.register_contract(None, PauseModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+
+    // Pause the token
+    pause_module.pause(&env, &admin, &PauseScope::Token(token.clone())).unwrap();
+
+    // Attempt to create an agreement for the paused token, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+fn test_resolve_dispute_while_paused() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let pause_module = env.register_contract(None, PauseModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+    let token = TokenClient::new(&env, &env.register_stellar_asset_contract(admin.clone()));
+    token.mint(&env.current_contract_address(), &1000);
+
+    // Create a transaction with an open dispute
+    let transaction = Transaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        token: token.address.clone(),
+        deadline: 10000,
+        state: TransactionState::Dispute,
+    };
+    env.storage().persistent().set(&transaction_id, &transaction);
+    let dispute = Dispute {
+        transaction_id: transaction_id.clone(),
+        raiser: buyer.clone(),
+        reason: DisputeReason::Other,
+        remedy: ClaimedRemedy::Release,
+        panel: vec![&env, arbitrator.clone()],
+        votes: Vec::new(&env),
+        raised_at: 0,
+        state: DisputeState::Open,
+        bond: 0,
+        seller_bps: None,
+    };
+    env.storage().persistent().set(&("dispute", &transaction_id), &dispute);
+
+    // A global pause must not block dispute resolution
+    pause_module.pause(&env, &admin, &PauseScope::Global).unwrap();
+    dispute_resolution_module.resolve_dispute(&env, &transaction_id, &arbitrator, &true).unwrap();
+
+    let updated_dispute = dispute_resolution_module.get_dispute(&env, &transaction_id).unwrap();
+    assert_eq!(updated_dispute.state, DisputeState::Resolved);
+}
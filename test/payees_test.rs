@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, Symbol};
+use super::common::{funded_transaction, test_token};
+use crate::payees::BPS_DENOMINATOR;
+
+#[test]
+fn test_release_splits_across_payees() {
+    let env = Env::default();
+    let payee_module = env.register_contract(None, PayeeModule);
+    let fund_management_module = env.register_contract(None, FundManagementModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1001, TransactionState::Deposit);
+    let referrer = Address::generate(&env);
+    let logistics = Address::generate(&env);
+
+    // Seller 70%, referrer 20%, logistics 10%
+    let payees = vec![
+        &env,
+        Payee { address: transaction.seller.clone(), share_bps: 7000 },
+        Payee { address: referrer.clone(), share_bps: 2000 },
+        Payee { address: logistics.clone(), share_bps: 1000 },
+    ];
+    payee_module.set_payees(&env, &transaction_id, &payees).unwrap();
+
+    // 1001 splits into 700 + 200 + 100 with 1 unit of dust
+    assert_eq!(payee_module.get_payees(&env, &transaction_id).unwrap().dust, 1);
+
+    fund_management_module.release_funds(&env, &transaction).unwrap();
+
+    // The dust goes to the first payee
+    let token_client = TokenClient::new(&env, &transaction.token);
+    assert_eq!(token_client.balance(&transaction.seller), 701);
+    assert_eq!(token_client.balance(&referrer), 200);
+    assert_eq!(token_client.balance(&logistics), 100);
+}
+
+#[test]
+#[should_panic(expected = "InvalidShares")]
+fn test_set_payees_invalid_shares() {
+    let env = Env::default();
+    let payee_module = env.register_contract(None, PayeeModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+
+    // Shares totalling 90% should panic
+    let payees = vec![
+        &env,
+        Payee { address: transaction.seller.clone(), share_bps: 6000 },
+        Payee { address: Address::generate(&env), share_bps: 3000 },
+    ];
+    payee_module.set_payees(&env, &transaction_id, &payees).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidShares")]
+fn test_set_payees_overflowing_shares() {
+    let env = Env::default();
+    let payee_module = env.register_contract(None, PayeeModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+
+    // Shares that wrap around u32 to 10000 should panic
+    let payees = vec![
+        &env,
+        Payee { address: transaction.seller.clone(), share_bps: u32::MAX },
+        Payee { address: Address::generate(&env), share_bps: BPS_DENOMINATOR + 1 },
+    ];
+    payee_module.set_payees(&env, &transaction_id, &payees).unwrap();
+}
+
+#[test]
+fn test_settle_splits_seller_share_across_payees() {
+    let env = Env::default();
+    let payee_module = env.register_contract(None, PayeeModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let transaction = funded_transaction(&env, &transaction_id, &test_token(&env), 1000, TransactionState::Deposit);
+    let referrer = Address::generate(&env);
+
+    let payees = vec![
+        &env,
+        Payee { address: transaction.seller.clone(), share_bps: 8000 },
+        Payee { address: referrer.clone(), share_bps: 2000 },
+    ];
+    payee_module.set_payees(&env, &transaction_id, &payees).unwrap();
+
+    // A 50/50 settlement splits the seller's half across the payees
+    FundManagementModule::settle(&env, &transaction, BPS_DENOMINATOR / 2);
+
+    let token_client = TokenClient::new(&env, &transaction.token);
+    assert_eq!(token_client.balance(&transaction.buyer), 500);
+    assert_eq!(token_client.balance(&transaction.seller), 400);
+    assert_eq!(token_client.balance(&referrer), 100);
+}
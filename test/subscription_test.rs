@@ -0,0 +1,233 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, Symbol};
+use super::common::registered_arbitrator;
+use crate::dispute_resolution::DEFAULT_RULING_TIMEOUT;
+
+const PERIOD: u64 = 30 * 24 * 60 * 60;
+
+struct Setup {
+    buyer: Address,
+    seller: Address,
+    token_client: TokenClient<'static>,
+    subscription_id: Symbol,
+}
+
+fn setup(env: &Env, subscription_module: &Address) -> Setup {
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    DecentralizedEscrowService::initialize(env.clone(), admin.clone()).unwrap();
+
+    let buyer = Address::generate(env);
+    let seller = Address::generate(env);
+    let token = env.register_stellar_asset_contract(admin);
+    TokenModule::allow_token(env.clone(), token.clone()).unwrap();
+    let token_client = TokenClient::new(env, &token);
+    token_client.mint(&buyer, &3000);
+
+    // Fund three monthly installments of 1000
+    let subscription_id = Symbol::from_str("sub1");
+    subscription_module
+        .create_subscription(env, &subscription_id, &buyer, &seller, &token, &1000i128, &PERIOD, &3)
+        .unwrap();
+
+    Setup { buyer, seller, token_client, subscription_id }
+}
+
+#[test]
+fn test_release_installment_after_period_end() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+
+    // Move past the first period and release its installment
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += PERIOD + 1;
+    });
+    subscription_module.release_installment(&env, &s.subscription_id, &0).unwrap();
+
+    let subscription = subscription_module.get_subscription(&env, &s.subscription_id).unwrap();
+    assert_eq!(subscription.installments.get_unchecked(0), InstallmentState::Released);
+    assert_eq!(subscription.installments.get_unchecked(1), InstallmentState::Pending);
+    assert_eq!(s.token_client.balance(&s.seller), 1000);
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_release_installment_before_period_end() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+
+    // Attempt to release the second installment during the first period, which should panic
+    subscription_module.release_installment(&env, &s.subscription_id, &1).unwrap();
+}
+
+#[test]
+fn test_cancel_refunds_unused_periods() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+
+    // Cancel during the second period
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += PERIOD + 1;
+    });
+    subscription_module.cancel_subscription(&env, &s.subscription_id).unwrap();
+
+    // The first installment stays with the seller, the other two are refunded
+    let subscription = subscription_module.get_subscription(&env, &s.subscription_id).unwrap();
+    assert!(subscription.cancelled);
+    assert_eq!(subscription.installments.get_unchecked(0), InstallmentState::Pending);
+    assert_eq!(subscription.installments.get_unchecked(1), InstallmentState::Refunded);
+    assert_eq!(subscription.installments.get_unchecked(2), InstallmentState::Refunded);
+    assert_eq!(s.token_client.balance(&s.buyer), 2000);
+
+    subscription_module.release_installment(&env, &s.subscription_id, &0).unwrap();
+    assert_eq!(s.token_client.balance(&s.seller), 1000);
+}
+
+#[test]
+fn test_dispute_single_installment() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+    let arbitrator = registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += PERIOD + 1;
+    });
+
+    // Dispute the first installment and resolve it for the buyer
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+    let dispute = subscription_module.get_installment_dispute(&env, &s.subscription_id, &0).unwrap();
+    assert_eq!(dispute.panel, vec![&env, arbitrator.clone()]);
+    assert_eq!(ArbitratorModule::get_arbitrator(env.clone(), arbitrator.clone()).unwrap().open_disputes, 1);
+    subscription_module.resolve_installment(&env, &s.subscription_id, &0, &arbitrator, &false).unwrap();
+
+    let subscription = subscription_module.get_subscription(&env, &s.subscription_id).unwrap();
+    assert_eq!(subscription.installments.get_unchecked(0), InstallmentState::Refunded);
+    assert_eq!(subscription.installments.get_unchecked(1), InstallmentState::Pending);
+    assert_eq!(s.token_client.balance(&s.buyer), 1000);
+
+    // The ruling closes the case and counts towards the arbitrator's metrics
+    let dispute = subscription_module.get_installment_dispute(&env, &s.subscription_id, &0).unwrap();
+    assert_eq!(dispute.state, DisputeState::Resolved);
+    assert_eq!(ArbitratorModule::get_arbitrator(env.clone(), arbitrator.clone()).unwrap().open_disputes, 0);
+    assert_eq!(DisputeResolutionModule::get_arbitrator_metrics(env.clone(), arbitrator).resolved, 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_resolve_installment_by_unassigned_arbitrator() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+    registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+
+    // Resolving as an address off the panel should panic
+    subscription_module
+        .resolve_installment(&env, &s.subscription_id, &0, &Address::generate(&env), &false)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "NoArbitratorAvailable")]
+fn test_dispute_installment_without_eligible_arbitrator() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+
+    // The only arbitrator handles a different kind of dispute
+    registered_arbitrator(&env, vec![&env, DisputeReason::Fraud]);
+
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+}
+
+#[test]
+fn test_installment_dispute_bond_forfeited_by_losing_raiser() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let dispute_resolution_module = env.register_contract(None, DisputeResolutionModule);
+    let s = setup(&env, &subscription_module);
+    let arbitrator = registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    // A 10% bond on a 1000 installment
+    let policy = DisputeBondPolicy { amount: DisputeBondAmount::Percentage(1000), recipient: DisputeBondRecipient::Counterparty };
+    dispute_resolution_module.set_dispute_bond(&env, &Some(policy)).unwrap();
+    s.token_client.mint(&s.buyer, &100);
+
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+    assert_eq!(subscription_module.get_installment_dispute(&env, &s.subscription_id, &0).unwrap().bond, 100);
+    assert_eq!(s.token_client.balance(&s.buyer), 0);
+
+    // The buyer loses, so the seller gets the installment and the bond
+    subscription_module.resolve_installment(&env, &s.subscription_id, &0, &arbitrator, &true).unwrap();
+    assert_eq!(s.token_client.balance(&s.seller), 1100);
+}
+
+#[test]
+fn test_rule_installment_on_timeout_splits_without_votes() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+    registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.seller, &DisputeReason::Other, &ClaimedRemedy::Release)
+        .unwrap();
+
+    // Nobody votes before the ruling timeout passes
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp += DEFAULT_RULING_TIMEOUT + 1;
+    });
+    subscription_module.rule_installment_on_timeout(&env, &s.subscription_id, &0).unwrap();
+
+    let subscription = subscription_module.get_subscription(&env, &s.subscription_id).unwrap();
+    assert_eq!(subscription.installments.get_unchecked(0), InstallmentState::Released);
+    let dispute = subscription_module.get_installment_dispute(&env, &s.subscription_id, &0).unwrap();
+    assert_eq!(dispute.state, DisputeState::TimedOut);
+    assert_eq!(dispute.seller_bps, Some(5000));
+    assert_eq!(s.token_client.balance(&s.seller), 500);
+    assert_eq!(s.token_client.balance(&s.buyer), 500);
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_rule_installment_on_timeout_before_timeout() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+    registered_arbitrator(&env, vec![&env, DisputeReason::Other]);
+
+    subscription_module
+        .dispute_installment(&env, &s.subscription_id, &0, &s.buyer, &DisputeReason::Other, &ClaimedRemedy::Refund)
+        .unwrap();
+
+    // Ending the dispute while the panel still has time should panic
+    subscription_module.rule_installment_on_timeout(&env, &s.subscription_id, &0).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "ReservedTransactionId")]
+fn test_create_subscription_rejects_reserved_id() {
+    let env = Env::default();
+    let subscription_module = env.register_contract(None, SubscriptionModule);
+    let s = setup(&env, &subscription_module);
+
+    // Squatting on the key the first generated escrow would use should panic
+    subscription_module
+        .create_subscription(&env, &Symbol::from_str("esc_1"), &s.buyer, &s.seller, &s.token_client.address, &1000i128, &PERIOD, &1)
+        .unwrap();
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, Symbol};
+
+struct Setup {
+    buyer: Address,
+    seller: Address,
+    token_a: TokenClient<'static>,
+    token_b: TokenClient<'static>,
+    swap_id: Symbol,
+}
+
+fn setup(env: &Env, swap_module: &Address) -> Setup {
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    DecentralizedEscrowService::initialize(env.clone(), admin.clone()).unwrap();
+
+    let buyer = Address::generate(env);
+    let seller = Address::generate(env);
+    let token_a = TokenClient::new(env, &env.register_stellar_asset_contract(admin.clone()));
+    let token_b = TokenClient::new(env, &env.register_stellar_asset_contract(admin));
+    for token in [&token_a, &token_b] {
+        TokenModule::allow_token(env.clone(), token.address.clone()).unwrap();
+    }
+    token_a.mint(&buyer, &1000);
+    token_b.mint(&seller, &250);
+
+    // The buyer swaps 1000 of token A for the seller's 250 of token B
+    let swap_id = Symbol::from_str("swap1");
+    let buyer_leg = SwapLeg { party: buyer.clone(), token: token_a.address.clone(), amount: 1000, funding_deadline: 1000, funded: false };
+    let seller_leg = SwapLeg { party: seller.clone(), token: token_b.address.clone(), amount: 250, funding_deadline: 2000, funded: false };
+    swap_module.create_swap(env, &swap_id, &buyer_leg, &seller_leg).unwrap();
+
+    Setup { buyer, seller, token_a, token_b, swap_id }
+}
+
+#[test]
+fn test_swap_completes_when_both_legs_funded() {
+    let env = Env::default();
+    let swap_module = env.register_contract(None, SwapModule);
+    let s = setup(&env, &swap_module);
+
+    swap_module.fund_swap(&env, &s.swap_id, &s.buyer).unwrap();
+    assert_eq!(swap_module.get_swap(&env, &s.swap_id).unwrap().state, SwapState::Open);
+
+    // The second deposit triggers the exchange
+    swap_module.fund_swap(&env, &s.swap_id, &s.seller).unwrap();
+
+    assert_eq!(swap_module.get_swap(&env, &s.swap_id).unwrap().state, SwapState::Completed);
+    assert_eq!(s.token_a.balance(&s.seller), 1000);
+    assert_eq!(s.token_b.balance(&s.buyer), 250);
+}
+
+#[test]
+fn test_expire_swap_refunds_funded_side() {
+    let env = Env::default();
+    let swap_module = env.register_contract(None, SwapModule);
+    let s = setup(&env, &swap_module);
+
+    swap_module.fund_swap(&env, &s.swap_id, &s.buyer).unwrap();
+
+    // The seller misses its funding deadline
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 2001;
+    });
+    swap_module.expire_swap(&env, &s.swap_id).unwrap();
+
+    assert_eq!(swap_module.get_swap(&env, &s.swap_id).unwrap().state, SwapState::Expired);
+    assert_eq!(s.token_a.balance(&s.buyer), 1000);
+}
+
+#[test]
+#[should_panic(expected = "DeadlineNotReached")]
+fn test_expire_swap_before_deadlines() {
+    let env = Env::default();
+    let swap_module = env.register_contract(None, SwapModule);
+    let s = setup(&env, &swap_module);
+
+    swap_module.fund_swap(&env, &s.swap_id, &s.buyer).unwrap();
+
+    // The seller still has time to fund, so expiring should panic
+    swap_module.expire_swap(&env, &s.swap_id).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "DeadlineExceeded")]
+fn test_fund_swap_after_deadline() {
+    let env = Env::default();
+    let swap_module = env.register_contract(None, SwapModule);
+    let s = setup(&env, &swap_module);
+
+    env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 1001;
+    });
+
+    // The buyer's funding deadline has passed, so funding should panic
+    swap_module.fund_swap(&env, &s.swap_id, &s.buyer).unwrap();
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, Symbol};
+use super::common::{setup, funded_transaction, test_token};
+
+#[test]
+#[should_panic(expected = "TokenNotAllowed")]
+fn test_create_agreement_token_not_allowed() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    // Attempt to create an agreement in a token that is not allow-listed, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidAmount")]
+fn test_create_agreement_non_positive_amount() {
+    let env = Env::default();
+    setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = Address::generate(&env);
+    token_module.allow_token(&env, &token).unwrap();
+
+    // Attempt to create an agreement for zero, which should panic
+    agreement_module
+        .create_agreement(&env, &Symbol::from_str("txn1"), &buyer, &seller, &token, &0i128, &10000u64)
+        .unwrap();
+}
+
+#[test]
+fn test_native_token_escrow_amount() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let transaction_id = Symbol::from_str("txn1");
+
+    // Register the native asset contract, which allow-lists it
+    let native = env.register_stellar_asset_contract(admin);
+    token_module.set_native_token(&env, &native).unwrap();
+    assert!(token_module.is_token_allowed(&env, &native));
+    assert_eq!(token_module.get_native_token(&env), Some(native.clone()));
+
+    agreement_module
+        .create_agreement(&env, &transaction_id, &buyer, &seller, &native, &25_000_000i128, &10000u64)
+        .unwrap();
+
+    // Verify the display amount carries the token's decimals and symbol
+    let escrow_amount = token_module.get_escrow_amount(&env, &transaction_id).unwrap();
+    assert_eq!(escrow_amount.amount, 25_000_000);
+    assert_eq!(escrow_amount.decimals, 7);
+    assert_eq!(escrow_amount.symbol, token::Client::new(&env, &native).symbol());
+}
+
+#[test]
+fn test_escrow_amount_for_funded_transaction() {
+    let env = Env::default();
+    setup(&env);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let transaction_id = Symbol::from_str("txn1");
+    let token = test_token(&env);
+    funded_transaction(&env, &transaction_id, &token, 1000, TransactionState::Deposit);
+
+    // Funded transactions are read with their own layout
+    let escrow_amount = token_module.get_escrow_amount(&env, &transaction_id).unwrap();
+    assert_eq!(escrow_amount.amount, 1000);
+    assert_eq!(escrow_amount.decimals, token::Client::new(&env, &token).decimals());
+}
+
+#[test]
+fn test_disallow_token() {
+    let env = Env::default();
+    setup(&env);
+    let token_module = env.register_contract(None, TokenModule);
+
+    let token = Address::generate(&env);
+
+    token_module.allow_token(&env, &token).unwrap();
+    assert!(token_module.is_token_allowed(&env, &token));
+
+    token_module.disallow_token(&env, &token).unwrap();
+    assert!(!token_module.is_token_allowed(&env, &token));
+}
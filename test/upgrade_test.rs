@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Symbol};
+use super::common::{setup, allowed_token};
+use crate::upgrade::MAX_MIGRATION_BATCH;
+
+mod escrow_wasm {
+    soroban_sdk::contractimport!(
+        file = "target/wasm32-unknown-unknown/release/decentralized_escrow_service.wasm"
+    );
+}
+
+#[test]
+fn test_upgrade_populated_contract() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let agreement_module = env.register_contract(None, AgreementModule);
+    let upgrade_module = env.register_contract(None, UpgradeModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = allowed_token(&env);
+    let transaction_id = Symbol::from_str("txn1");
+
+    let legacy_id = Symbol::from_str("txn2");
+
+    // Populate the contract with a current agreement and a version 1 transaction
+    agreement_module
+        .create_agreement(&env, &transaction_id, &buyer, &seller, &token, &1000i128, &10000u64)
+        .unwrap();
+    env.storage().persistent().set(&legacy_id, &LegacyTransaction {
+        id: legacy_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 500,
+        token: Symbol::from_str("usdc"),
+        state: TransactionState::Deposit,
+    });
+
+    // Upgrade to the freshly built WASM and migrate both records
+    let wasm_hash = env.deployer().upload_contract_wasm(escrow_wasm::WASM);
+    upgrade_module.upgrade(&env, &admin, &wasm_hash).unwrap();
+    let ids = vec![&env, transaction_id.clone(), legacy_id.clone()];
+    assert_eq!(upgrade_module.migrate(&env, &admin, &token, &20000, &ids).unwrap(), 1);
+    upgrade_module.finish_migration(&env, &admin).unwrap();
+
+    // Verify the agreement survives untouched and the legacy record is converted
+    let agreement = agreement_module.get_agreement(&env, &transaction_id).unwrap();
+    assert_eq!(agreement.token, token);
+    assert_eq!(agreement.amount, 1000);
+    let migrated: Transaction = env.storage().persistent().get(&legacy_id).unwrap();
+    assert_eq!(migrated.token, token);
+    assert_eq!(migrated.deadline, 20000);
+}
+
+#[test]
+fn test_migrate_legacy_records() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let upgrade_module = env.register_contract(None, UpgradeModule);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let default_token = Address::generate(&env);
+    let agreement_id = Symbol::from_str("txn1");
+    let transaction_id = Symbol::from_str("txn2");
+
+    // Store records in the version 1 layout
+    env.storage().persistent().set(&agreement_id, &LegacyAgreement {
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 1000,
+        deadline: 10000,
+        state: TransactionState::Setup,
+    });
+    env.storage().persistent().set(&transaction_id, &LegacyTransaction {
+        id: transaction_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 500,
+        token: Symbol::from_str("usdc"),
+        state: TransactionState::Deposit,
+    });
+
+    // A record already in the current layout sits alongside them
+    let current_id = Symbol::from_str("txn3");
+    let current = Transaction {
+        id: current_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        amount: 700,
+        token: Address::generate(&env),
+        deadline: 30000,
+        state: TransactionState::Deposit,
+    };
+    env.storage().persistent().set(&current_id, &current);
+
+    // Migrate every record in one batch; only the two legacy records change
+    let ids = vec![&env, agreement_id.clone(), transaction_id.clone(), current_id.clone(), Symbol::from_str("none")];
+    assert_eq!(upgrade_module.migrate(&env, &admin, &default_token, &20000, &ids).unwrap(), 2);
+    upgrade_module.finish_migration(&env, &admin).unwrap();
+
+    // Verify the records were converted to the current layout
+    let agreement: Agreement = env.storage().persistent().get(&agreement_id).unwrap();
+    assert_eq!(agreement.token, default_token);
+    assert_eq!(agreement.amount, 1000);
+    let transaction: Transaction = env.storage().persistent().get(&transaction_id).unwrap();
+    assert_eq!(transaction.token, default_token);
+    assert_eq!(transaction.state, TransactionState::Deposit);
+    assert_eq!(transaction.deadline, 20000);
+    let unchanged: Transaction = env.storage().persistent().get(&current_id).unwrap();
+    assert_eq!(unchanged, current);
+    assert_eq!(upgrade_module.schema_version(&env), CURRENT_SCHEMA_VERSION);
+
+    // Only the funded legacy transaction counts towards the escrowed total
+    assert_eq!(AccountingModule::get_accounting(env.clone(), default_token).escrowed, 500);
+}
+
+#[test]
+#[should_panic(expected = "BatchTooLarge")]
+fn test_migrate_batch_too_large() {
+    let env = Env::default();
+    let admin = setup(&env);
+    let upgrade_module = env.register_contract(None, UpgradeModule);
+
+    // Build a batch one larger than the limit
+    let mut ids = vec![&env];
+    for _ in 0..=MAX_MIGRATION_BATCH {
+        ids.push_back(Symbol::from_str("txn"));
+    }
+
+    upgrade_module.migrate(&env, &admin, &Address::generate(&env), &10000, &ids).unwrap();
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{Env, Timepoint};
+use soroban_sdk::{Env, Symbol, Timepoint};
 
 #[test]
 fn test_calculate_deadline() {
@@ -37,3 +37,13 @@ fn test_is_past_deadline() {
     let future_deadline = 6000;
     assert!(!Utils::is_past_deadline(&env, future_deadline));
 }
+
+#[test]
+fn test_escrow_symbol() {
+    let env = Env::default();
+
+    // Verify generated IDs map to stable symbols
+    assert_eq!(Utils::escrow_symbol(&env, 0), Symbol::new(&env, "esc_0"));
+    assert_eq!(Utils::escrow_symbol(&env, 42), Symbol::new(&env, "esc_42"));
+    assert_eq!(Utils::escrow_symbol(&env, u64::MAX), Symbol::new(&env, "esc_18446744073709551615"));
+}